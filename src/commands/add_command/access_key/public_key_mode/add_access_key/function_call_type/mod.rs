@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
+use dialoguer::{console::Term, theme::ColorfulTheme, Input, MultiSelect, Select};
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
@@ -49,6 +49,12 @@ impl FunctionCallType {
             },
             None => Self::input_receiver_account_id(&context)?,
         };
+        let exported_functions = match &connection_config {
+            Some(network_connection_config) => {
+                get_exported_functions(network_connection_config, &receiver_account_id)
+            }
+            None => None,
+        };
         let method_names: crate::types::vec_string::VecString = match optional_clap_variant
             .clone()
             .and_then(|clap_variant| clap_variant.method_names)
@@ -60,8 +66,20 @@ impl FunctionCallType {
                     cli_method_names
                 }
             }
-            None => FunctionCallType::input_method_names(&context)?,
+            None => FunctionCallType::input_method_names(&context, exported_functions.as_deref())?,
         };
+        if let Some(exported_functions) = &exported_functions {
+            for method_name in get_not_exported_method_names(&method_names.0, exported_functions) {
+                println!(
+                    "WARNING! The method <{}> is not exported by the contract <{}>, so the access key can not call it.",
+                    method_name, receiver_account_id
+                );
+            }
+        }
+        match &allowance {
+            Some(allowance) => println!("The access key allowance: {}", allowance),
+            None => println!("The access key allowance: unlimited (the key can spend the whole account balance on fees)"),
+        }
         let sign_option = match optional_clap_variant.and_then(|clap_variant| clap_variant.sign_option) {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from_cli(Some(cli_sign_transaction), context)?,
             None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_variant(context)?,
@@ -78,8 +96,33 @@ impl FunctionCallType {
 impl FunctionCallType {
     pub fn input_method_names(
         _context: &crate::common::SignerContext,
+        exported_functions: Option<&[String]>,
     ) -> color_eyre::eyre::Result<crate::types::vec_string::VecString> {
         println!();
+        if let Some(exported_functions) = exported_functions {
+            let choose_input = vec![
+                "Yes, I want to choose the method names from the contract exports",
+                "No, I want to type a list of method names",
+            ];
+            let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("The receiver has a contract. Do you want to choose the method names that can be used from its exported functions?")
+                .items(&choose_input)
+                .default(0)
+                .interact_on_opt(&Term::stderr())?;
+            if let Some(0) = select_choose_input {
+                let selected_method_names = MultiSelect::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Choose the method names that will be allowed to be called in a transaction signed by this access key (Space to select, Enter to confirm; if none is selected, any method can be called)")
+                    .items(exported_functions)
+                    .interact_on_opt(&Term::stderr())?
+                    .unwrap_or_default();
+                return Ok(crate::types::vec_string::VecString(
+                    selected_method_names
+                        .into_iter()
+                        .map(|index| exported_functions[index].clone())
+                        .collect(),
+                ));
+            }
+        }
         let choose_input = vec![
             "Yes, I want to input a list of method names that can be used",
             "No, I don't to input a list of method names that can be used",
@@ -214,3 +257,55 @@ impl FunctionCallType {
         Ok(())
     }
 }
+
+/// Fetches the functions exported by the receiver contract, so that the method names of the
+/// access key can be chosen from them and checked against them.
+fn get_exported_functions(
+    connection_config: &crate::common::ConnectionConfig,
+    receiver_account_id: &crate::types::account_id::AccountId,
+) -> Option<Vec<String>> {
+    match actix::System::new().block_on(crate::common::get_contract_exported_functions(
+        connection_config,
+        receiver_account_id.clone().into(),
+    )) {
+        Ok(exported_functions) => exported_functions,
+        Err(err) => {
+            println!(
+                "The method names can not be checked against the contract <{}>: {}",
+                receiver_account_id, err
+            );
+            None
+        }
+    }
+}
+
+fn get_not_exported_method_names<'a>(
+    method_names: &'a [String],
+    exported_functions: &[String],
+) -> Vec<&'a str> {
+    method_names
+        .iter()
+        .filter(|method_name| !method_name.is_empty() && !exported_functions.contains(*method_name))
+        .map(|method_name| method_name.as_str())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_exported_method_names() {
+        let exported_functions = vec!["ft_transfer".to_string(), "ft_balance_of".to_string()];
+        let method_names = vec![
+            "ft_transfer".to_string(),
+            "ft_tranfser".to_string(),
+            "".to_string(),
+        ];
+        assert_eq!(
+            get_not_exported_method_names(&method_names, &exported_functions),
+            vec!["ft_tranfser"]
+        );
+        assert!(get_not_exported_method_names(&[], &exported_functions).is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
+
+/// the deposit for the new account
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliTransferNEARTokensAction {
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransferNEARTokensAction {
+    pub amount: crate::common::TransferAmount,
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl interactive_clap::ToCli for TransferNEARTokensAction {
+    type CliVariant = CliTransferNEARTokensAction;
+}
+
+impl CliTransferNEARTokensAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(amount) = &self.amount {
+            args.push_front(amount.to_string());
+        }
+        args
+    }
+}
+
+impl From<TransferNEARTokensAction> for CliTransferNEARTokensAction {
+    fn from(transfer_near_tokens_action: TransferNEARTokensAction) -> Self {
+        Self {
+            amount: Some(transfer_near_tokens_action.amount.into()),
+            sign_option: Some(transfer_near_tokens_action.sign_option.into()),
+        }
+    }
+}
+
+impl TransferNEARTokensAction {
+    pub fn from_cli(
+        optional_clap_variant: Option<
+            <TransferNEARTokensAction as interactive_clap::ToCli>::CliVariant,
+        >,
+        context: crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let amount: crate::common::TransferAmount = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.amount)
+        {
+            Some(cli_amount) => crate::common::TransferAmount::from_unchecked(cli_amount),
+            None => TransferNEARTokensAction::input_amount(&context)?,
+        };
+
+        let sign_option = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.sign_option)
+        {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from_cli(Some(cli_sign_transaction), context)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_variant(context)?,
+        };
+
+        Ok(Self {
+            amount,
+            sign_option,
+        })
+    }
+}
+
+impl TransferNEARTokensAction {
+    fn input_amount(
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::common::TransferAmount> {
+        let connection_config = context.connection_config.clone();
+        let sender_account_id = context.signer_account_id.clone();
+        match connection_config {
+            Some(connection_config) => loop {
+                let account_transfer_allowance = crate::common::get_account_transfer_allowance(
+                    &connection_config,
+                    sender_account_id.clone().into(),
+                )?;
+                println! {"{}", &account_transfer_allowance};
+                let input_amount: crate::common::NearBalance = Input::new()
+                        .with_prompt("How many NEAR Tokens do you want to deposit to the new account? (example: 10NEAR or 0.5near or 10000yoctonear)")
+                        .interact_text()
+                        ?;
+                if let Ok(transfer_amount) = crate::common::TransferAmount::from(
+                    input_amount.clone(),
+                    &account_transfer_allowance,
+                ) {
+                    break Ok(transfer_amount);
+                } else {
+                    let account_transfer_allowance = crate::common::get_account_transfer_allowance(
+                        &connection_config,
+                        sender_account_id.clone().into(),
+                    )?;
+                    println!(
+                        "\nWARNING! There is only {} available for transfer.",
+                        account_transfer_allowance.transfer_allowance()
+                    );
+                    let choose_input = vec![
+                        format!("Yes, I'd like to transfer {}.", input_amount),
+                        "No, I'd like to change the transfer amount.".to_string(),
+                    ];
+                    let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+                        .with_prompt("Do you want to keep this amount for the transfer?")
+                        .items(&choose_input)
+                        .default(0)
+                        .interact_on_opt(&Term::stderr())?;
+                    match select_choose_input {
+                        Some(0) => {
+                            break Ok(crate::common::TransferAmount::from_unchecked(input_amount))
+                        }
+                        Some(1) => {}
+                        _ => unreachable!("Error"),
+                    }
+                }
+            },
+            None => {
+                let input_amount: crate::common::NearBalance = Input::new()
+                        .with_prompt("How many NEAR Tokens do you want to deposit to the new account? (example: 10NEAR or 0.5near or 10000yoctonear)")
+                        .interact_text()
+                        ?;
+                Ok(crate::common::TransferAmount::from_unchecked(input_amount))
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+        new_account_id: near_primitives::types::AccountId,
+        new_public_key: near_crypto::PublicKey,
+        new_key_file_paths: Vec<std::path::PathBuf>,
+    ) -> crate::CliResult {
+        let action = near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: "create_account".to_string(),
+                args: serde_json::json!({
+                    "new_account_id": new_account_id.to_string(),
+                    "new_public_key": new_public_key.to_string(),
+                })
+                .to_string()
+                .into_bytes(),
+                gas: super::CREATE_ACCOUNT_GAS,
+                deposit: self.amount.to_yoctonear(),
+            },
+        );
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(action);
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(unsigned_transaction, network_connection_config.clone())
+            .await?
+        {
+            Some(transaction_info) => {
+                // The registrar returns `false` and refunds the deposit when it fails to create
+                // the account (e.g. the account already exists), the transaction itself succeeds.
+                let is_account_created = match &transaction_info.status {
+                    near_primitives::views::FinalExecutionStatus::SuccessValue(value) => {
+                        base64::decode(value).map_or(true, |value| value != b"false")
+                    }
+                    near_primitives::views::FinalExecutionStatus::Failure(_) => false,
+                    _ => true,
+                };
+                let is_refunded = matches!(
+                    transaction_info.status,
+                    near_primitives::views::FinalExecutionStatus::SuccessValue(_)
+                );
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    network_connection_config,
+                );
+                if is_account_created {
+                    println!("Account <{}> has been created.", new_account_id);
+                } else {
+                    if is_refunded {
+                        println!(
+                            "Account <{}> has not been created (it may already exist), the deposit is refunded.",
+                            new_account_id
+                        );
+                    }
+                    crate::common::remove_new_account_key_files(&new_key_file_paths);
+                }
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+mod public_key_mode;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct AccountFullAccess {
+    #[interactive_clap(subcommand)]
+    pub public_key_mode: self::public_key_mode::PublicKeyMode,
+}
+
+impl AccountFullAccess {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+        new_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        self.public_key_mode
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                new_account_id,
+            )
+            .await
+    }
+}
@@ -0,0 +1,37 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct AddAccessKeyAction {
+    pub public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(named_arg)]
+    ///Enter an amount
+    pub deposit: super::super::super::deposit::TransferNEARTokensAction,
+}
+
+impl AddAccessKeyAction {
+    fn input_public_key(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
+        Ok(Input::new()
+            .with_prompt("Enter a public key for this access key")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+        new_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        self.deposit
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                new_account_id,
+                self.public_key.into(),
+                vec![],
+            )
+            .await
+    }
+}
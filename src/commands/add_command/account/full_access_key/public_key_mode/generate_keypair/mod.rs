@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct GenerateKeypair {
+    #[interactive_clap(named_arg)]
+    ///Enter an amount
+    pub deposit: super::super::super::deposit::TransferNEARTokensAction,
+}
+
+impl GenerateKeypair {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+        new_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let key_pair_properties: crate::common::KeyPairProperties =
+            crate::common::generate_keypair().await?;
+        let new_key_file_paths = crate::common::save_access_key_to_keychain(
+            network_connection_config.clone(),
+            key_pair_properties.clone(),
+            &new_account_id.to_string(),
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })?;
+        self.deposit
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                new_account_id,
+                near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?,
+                new_key_file_paths,
+            )
+            .await
+    }
+}
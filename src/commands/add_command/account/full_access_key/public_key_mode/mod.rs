@@ -0,0 +1,47 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod add_full_access_key;
+mod generate_keypair;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = crate::common::SignerContext)]
+///Add a full access key for the new account
+pub enum PublicKeyMode {
+    #[strum_discriminants(strum(message = "Enter public key"))]
+    /// Enter public key
+    PublicKey(self::add_full_access_key::AddAccessKeyAction),
+    #[strum_discriminants(strum(message = "Generate key pair"))]
+    /// Generate key pair
+    GenerateKeypair(self::generate_keypair::GenerateKeypair),
+}
+
+impl PublicKeyMode {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+        new_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        match self {
+            PublicKeyMode::PublicKey(add_access_key_action) => {
+                add_access_key_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        new_account_id,
+                    )
+                    .await
+            }
+            PublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
+                cli_generate_keypair
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        new_account_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod deposit;
+mod full_access_key;
+pub mod operation_mode;
+mod receiver;
+mod sender;
+
+const CREATE_ACCOUNT_GAS: u64 = 30_000_000_000_000; // 30 TeraGas
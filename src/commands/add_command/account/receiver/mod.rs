@@ -0,0 +1,116 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct NewAccount {
+    #[interactive_clap(skip_default_from_cli)]
+    pub new_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    ///Specify a full access key for the new account
+    pub account_full_access: super::full_access_key::AccountFullAccess,
+}
+
+impl NewAccount {
+    fn from_cli_new_account_id(
+        optional_cli_new_account_id: Option<crate::types::account_id::AccountId>,
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_new_account_id {
+            Some(cli_new_account_id) => {
+                if Self::is_new_account_id_valid(context, &cli_new_account_id)? {
+                    Ok(cli_new_account_id)
+                } else {
+                    Self::input_new_account_id(context)
+                }
+            }
+            None => Self::input_new_account_id(context),
+        }
+    }
+
+    fn input_new_account_id(
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the new account ID? (example: alice.testnet)")
+                .interact_text()?;
+            if Self::is_new_account_id_valid(context, &account_id)? {
+                break Ok(account_id);
+            }
+        }
+    }
+
+    fn is_new_account_id_valid(
+        context: &crate::common::SignerContext,
+        new_account_id: &crate::types::account_id::AccountId,
+    ) -> color_eyre::eyre::Result<bool> {
+        if let Err(err) = get_registrar_account_id(&new_account_id.clone().into()) {
+            println!("{}", err);
+            return Ok(false);
+        }
+        if let Some(connection_config) = &context.connection_config {
+            if crate::common::get_account_state(connection_config, new_account_id.clone().into())?
+                .is_some()
+            {
+                println!("Account <{}> already exists", new_account_id);
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let new_account_id: near_primitives::types::AccountId = self.new_account_id.into();
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: get_registrar_account_id(&new_account_id)?,
+            ..prepopulated_unsigned_transaction
+        };
+        self.account_full_access
+            .process(
+                unsigned_transaction,
+                network_connection_config,
+                new_account_id,
+            )
+            .await
+    }
+}
+
+/// Returns the top-level account (e.g. `testnet` or `near`) that creates `<name>.<top-level account>`
+/// accounts with its `create_account` method.
+fn get_registrar_account_id(
+    new_account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    let new_account_id = new_account_id.to_string();
+    match new_account_id.split('.').collect::<Vec<_>>().as_slice() {
+        [_, registrar_account_id] => Ok(registrar_account_id.parse()?),
+        _ => Err(color_eyre::Report::msg(format!(
+            "<{}> is not a named account of a top-level account (e.g. alice.testnet), use `add sub-account` to create sub-accounts",
+            new_account_id
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registrar_account_id() {
+        let registrar_account_id =
+            |account_id: &str| get_registrar_account_id(&account_id.parse().unwrap());
+        assert_eq!(
+            registrar_account_id("alice.testnet").unwrap().to_string(),
+            "testnet"
+        );
+        assert_eq!(
+            registrar_account_id("alice.near").unwrap().to_string(),
+            "near"
+        );
+        assert!(registrar_account_id("alice").is_err());
+        assert!(registrar_account_id("bob.alice.testnet").is_err());
+    }
+}
@@ -3,7 +3,7 @@ use dialoguer::Input;
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
 pub struct CallFunctionAction {
-    method_name: String,
+    pub method_name: String,
     args: String,
     #[interactive_clap(long = "prepaid-gas")]
     gas: crate::common::NearGas,
@@ -15,7 +15,7 @@ pub struct CallFunctionAction {
 }
 
 impl CallFunctionAction {
-    fn input_method_name(
+    pub fn input_method_name(
         _context: &crate::common::SignerContext,
     ) -> color_eyre::eyre::Result<String> {
         println!();
@@ -24,7 +24,7 @@ impl CallFunctionAction {
             .interact_text()?)
     }
 
-    fn input_gas(
+    pub fn input_gas(
         _context: &crate::common::SignerContext,
     ) -> color_eyre::eyre::Result<crate::common::NearGas> {
         println!();
@@ -45,14 +45,14 @@ impl CallFunctionAction {
         Ok(gas.into())
     }
 
-    fn input_args(_context: &crate::common::SignerContext) -> color_eyre::eyre::Result<String> {
+    pub fn input_args(_context: &crate::common::SignerContext) -> color_eyre::eyre::Result<String> {
         println!();
         Ok(Input::new()
             .with_prompt("Enter args for function")
             .interact_text()?)
     }
 
-    fn input_deposit(
+    pub fn input_deposit(
         _context: &crate::common::SignerContext,
     ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
         println!();
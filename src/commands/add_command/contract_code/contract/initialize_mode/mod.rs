@@ -1,6 +1,6 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
-mod call_function_type;
+pub mod call_function_type;
 
 #[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
@@ -15,6 +15,13 @@ pub enum NextAction {
 }
 
 impl NextAction {
+    pub fn init_method_name(&self) -> Option<&str> {
+        match self {
+            NextAction::Initialize(call_function_action) => Some(&call_function_action.method_name),
+            NextAction::NoInitialize(_) => None,
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -1,16 +1,56 @@
-use dialoguer::Input;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
 
-mod initialize_mode;
+pub mod initialize_mode;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct ContractFile {
     pub file_path: crate::types::path_buf::PathBuf,
+    /// Don't send the transaction if the same contract code is already deployed
+    #[interactive_clap(long)]
+    pub skip_if_unchanged: Option<bool>,
     #[interactive_clap(subcommand)]
     next_action: self::initialize_mode::NextAction,
 }
 
 impl ContractFile {
+    pub fn from_cli(
+        optional_clap_variant: Option<<ContractFile as interactive_clap::ToCli>::CliVariant>,
+        context: crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let cli_file_path = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.file_path);
+        // In the interactive mode the user is asked what to do only if the code is unchanged,
+        // otherwise the contract code is always deployed (unless `--skip-if-unchanged true` is set)
+        let is_interactive = cli_file_path.is_none();
+        let file_path = match cli_file_path {
+            Some(cli_file_path) => cli_file_path,
+            None => Self::input_file_path(&context)?,
+        };
+        let skip_if_unchanged = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.skip_if_unchanged)
+        {
+            Some(cli_skip_if_unchanged) => Some(cli_skip_if_unchanged),
+            None if is_interactive => None,
+            None => Some(false),
+        };
+        let next_action =
+            match optional_clap_variant.and_then(|clap_variant| clap_variant.next_action) {
+                Some(cli_next_action) => {
+                    self::initialize_mode::NextAction::from_cli(Some(cli_next_action), context)?
+                }
+                None => self::initialize_mode::NextAction::choose_variant(context)?,
+            };
+        Ok(Self {
+            file_path,
+            skip_if_unchanged,
+            next_action,
+        })
+    }
+
     fn input_file_path(
         _context: &crate::common::SignerContext,
     ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
@@ -33,6 +73,49 @@ impl ContractFile {
                 err
             ))
         })?;
+        crate::common::check_contract_code(
+            &code,
+            network_connection_config.as_ref(),
+            self.next_action.init_method_name(),
+        )
+        .await?;
+        if let Some(network_connection_config) = &network_connection_config {
+            let account_view = crate::common::get_account_view(
+                network_connection_config,
+                prepopulated_unsigned_transaction.receiver_id.clone(),
+                near_primitives::types::Finality::Final.into(),
+            )
+            .await?;
+            if account_view.code_hash == near_primitives::hash::hash(&code) {
+                println!(
+                    "The contract code on <{}> is up to date (SHA-256 checksum (hex): {}).",
+                    prepopulated_unsigned_transaction.receiver_id,
+                    hex::encode(account_view.code_hash.as_ref())
+                );
+                let skip_if_unchanged = match self.skip_if_unchanged {
+                    Some(skip_if_unchanged) => skip_if_unchanged,
+                    None => {
+                        let choices = ["No, skip the transaction", "Yes, deploy it anyway"];
+                        Select::with_theme(&ColorfulTheme::default())
+                            .with_prompt("Do you want to deploy the same contract code again?")
+                            .items(&choices)
+                            .default(0)
+                            .interact()?
+                            == 0
+                    }
+                };
+                if skip_if_unchanged {
+                    match self.next_action.init_method_name() {
+                        Some(init_method_name) => println!(
+                            "The transaction was not sent, the <{}> initialize call was skipped as well.",
+                            init_method_name
+                        ),
+                        None => println!("The transaction was not sent."),
+                    }
+                    return Ok(());
+                }
+            }
+        }
         let action = near_primitives::transaction::Action::DeployContract(
             near_primitives::transaction::DeployContractAction { code },
         );
@@ -1,3 +1,3 @@
-mod contract;
+pub mod contract;
 pub mod operation_mode;
 mod sender;
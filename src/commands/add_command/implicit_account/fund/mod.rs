@@ -0,0 +1,2 @@
+pub mod operation_mode;
+mod sender;
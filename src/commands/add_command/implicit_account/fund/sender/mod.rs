@@ -0,0 +1,154 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::AddImplicitAccountCommandNetworkContext)]
+#[interactive_clap(output_context = crate::common::SignerContext)]
+pub struct Sender {
+    #[interactive_clap(skip_default_from_cli)]
+    pub sender_account_id: crate::types::account_id::AccountId,
+    pub amount: crate::common::NearBalance,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+struct SenderContext {
+    connection_config: crate::common::ConnectionConfig,
+    sender_account_id: crate::types::account_id::AccountId,
+}
+
+impl SenderContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::AddImplicitAccountCommandNetworkContext,
+        scope: &<Sender as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            sender_account_id: scope.sender_account_id.clone(),
+        }
+    }
+}
+
+impl From<SenderContext> for crate::common::SignerContext {
+    fn from(item: SenderContext) -> Self {
+        Self {
+            connection_config: Some(item.connection_config),
+            signer_account_id: item.sender_account_id,
+        }
+    }
+}
+
+impl Sender {
+    fn from_cli_sender_account_id(
+        optional_cli_sender_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::AddImplicitAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_sender_account_id {
+            Some(cli_sender_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_sender_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_sender_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_sender_account_id);
+                    Sender::input_sender_account_id(&context)
+                }
+            },
+            None => Self::input_sender_account_id(&context),
+        }
+    }
+
+    fn input_sender_account_id(
+        context: &super::operation_mode::online_mode::select_server::AddImplicitAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("Which account funds the implicit account?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+
+    fn input_amount(
+        _context: &super::operation_mode::online_mode::select_server::AddImplicitAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
+            .with_prompt("How many NEAR Tokens do you want to transfer to the implicit account? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let key_pair_properties = crate::common::generate_keypair().await?;
+        let implicit_account_id = key_pair_properties.implicit_account_id.clone();
+        // The key is saved before the transfer, so the funds are never sent to an account
+        // whose key is lost.
+        crate::common::save_access_key_to_keychain(
+            Some(network_connection_config.clone()),
+            key_pair_properties,
+            &implicit_account_id.to_string(),
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })?;
+
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.sender_account_id.clone().into(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: implicit_account_id.clone(),
+            block_hash: Default::default(),
+            actions: vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction {
+                    deposit: self.amount.to_yoctonear(),
+                },
+            )],
+        };
+        let transaction_info = match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => transaction_info,
+            None => return Ok(()),
+        };
+        let is_successful = !matches!(
+            transaction_info.status,
+            near_primitives::views::FinalExecutionStatus::Failure(_)
+        );
+        crate::common::print_transaction_status(
+            transaction_info,
+            Some(network_connection_config.clone()),
+        );
+        if is_successful {
+            let block_reference: near_primitives::types::BlockReference =
+                near_primitives::types::Finality::Final.into();
+            crate::common::display_account_info(
+                implicit_account_id.clone(),
+                &network_connection_config,
+                block_reference.clone(),
+            )
+            .await?;
+            crate::common::display_access_key_list(
+                implicit_account_id,
+                &network_connection_config,
+                block_reference,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod fund;
 mod generate_keypair;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
@@ -23,6 +24,11 @@ pub enum PublicKeyMode {
     #[strum_discriminants(strum(message = "Generate key pair"))]
     /// Generate key pair
     GenerateKeypair(self::generate_keypair::CliGenerateKeypair),
+    #[strum_discriminants(strum(
+        message = "Generate key pair and fund the implicit account with a transfer"
+    ))]
+    /// Generate key pair, save it in the network keychain and fund the implicit account with a transfer
+    GenerateKeypairAndFund(self::fund::operation_mode::OperationMode),
 }
 
 impl PublicKeyMode {
@@ -31,6 +37,7 @@ impl PublicKeyMode {
             PublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
                 cli_generate_keypair.process().await
             }
+            PublicKeyMode::GenerateKeypairAndFund(operation_mode) => operation_mode.process().await,
         }
     }
 }
@@ -1,6 +1,7 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod access_key;
+mod account;
 mod contract_code;
 mod implicit_account;
 mod stake_proposal;
@@ -30,6 +31,11 @@ pub enum Action {
     #[strum_discriminants(strum(message = "Add a new access key for an account"))]
     ///Add a new access key for an account
     AccessKey(self::access_key::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Add a new top-level named account (e.g. alice.testnet) through the registrar"
+    ))]
+    ///Add a new named account through the registrar contract of the top-level account
+    Account(self::account::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Add a new contract code"))]
     ///Add a contract code
     ContractCode(self::contract_code::operation_mode::OperationMode),
@@ -55,6 +61,11 @@ impl Action {
                     .process(prepopulated_unsigned_transaction)
                     .await
             }
+            Action::Account(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
             Action::ContractCode(operation_mode) => {
                 operation_mode
                     .process(prepopulated_unsigned_transaction)
@@ -1,5 +1,7 @@
 use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
 
+use super::super::contract_code::contract::initialize_mode::call_function_type::CallFunctionAction;
+
 /// creating a transfer of tokens
 #[derive(Debug, Default, Clone, clap::Clap)]
 #[clap(
@@ -9,6 +11,18 @@ use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
 )]
 pub struct CliTransferNEARTokensAction {
     amount: Option<crate::common::NearBalance>,
+    /// Deploy the contract code from this file to the sub-account in the same transaction
+    #[clap(long)]
+    contract_file: Option<crate::types::path_buf::PathBuf>,
+    /// Call this method to initialize the contract in the same transaction
+    #[clap(long)]
+    init_method_name: Option<String>,
+    #[clap(long)]
+    init_args: Option<String>,
+    #[clap(long)]
+    init_prepaid_gas: Option<crate::common::NearGas>,
+    #[clap(long)]
+    init_attached_deposit: Option<crate::common::NearBalance>,
     #[clap(subcommand)]
     sign_option: Option<
         crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
@@ -18,10 +32,26 @@ pub struct CliTransferNEARTokensAction {
 #[derive(Debug, Clone)]
 pub struct TransferNEARTokensAction {
     pub amount: crate::common::TransferAmount,
+    pub contract: Option<SubAccountContract>,
     pub sign_option:
         crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
 }
 
+/// The contract code deployed to the new sub-account and its initialization call.
+#[derive(Debug, Clone)]
+pub struct SubAccountContract {
+    pub file_path: crate::types::path_buf::PathBuf,
+    pub init_function_call: Option<InitFunctionCall>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InitFunctionCall {
+    pub method_name: String,
+    pub args: String,
+    pub gas: crate::common::NearGas,
+    pub deposit: crate::common::NearBalance,
+}
+
 impl interactive_clap::ToCli for TransferNEARTokensAction {
     type CliVariant = CliTransferNEARTokensAction;
 }
@@ -33,6 +63,26 @@ impl CliTransferNEARTokensAction {
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
             .unwrap_or_default();
+        if let Some(init_attached_deposit) = &self.init_attached_deposit {
+            args.push_front(init_attached_deposit.to_string());
+            args.push_front("--init-attached-deposit".to_string());
+        }
+        if let Some(init_prepaid_gas) = &self.init_prepaid_gas {
+            args.push_front(init_prepaid_gas.to_string());
+            args.push_front("--init-prepaid-gas".to_string());
+        }
+        if let Some(init_args) = &self.init_args {
+            args.push_front(init_args.to_string());
+            args.push_front("--init-args".to_string());
+        }
+        if let Some(init_method_name) = &self.init_method_name {
+            args.push_front(init_method_name.to_string());
+            args.push_front("--init-method-name".to_string());
+        }
+        if let Some(contract_file) = &self.contract_file {
+            args.push_front(contract_file.0.display().to_string());
+            args.push_front("--contract-file".to_string());
+        }
         if let Some(amount) = &self.amount {
             args.push_front(amount.to_string());
         }
@@ -42,8 +92,24 @@ impl CliTransferNEARTokensAction {
 
 impl From<TransferNEARTokensAction> for CliTransferNEARTokensAction {
     fn from(transfer_near_tokens_action: TransferNEARTokensAction) -> Self {
+        let (contract_file, init_function_call) = match transfer_near_tokens_action.contract {
+            Some(contract) => (Some(contract.file_path), contract.init_function_call),
+            None => (None, None),
+        };
         Self {
             amount: Some(transfer_near_tokens_action.amount.into()),
+            contract_file,
+            init_method_name: init_function_call
+                .as_ref()
+                .map(|init_function_call| init_function_call.method_name.clone()),
+            init_args: init_function_call
+                .as_ref()
+                .map(|init_function_call| init_function_call.args.clone()),
+            init_prepaid_gas: init_function_call
+                .as_ref()
+                .map(|init_function_call| init_function_call.gas.clone()),
+            init_attached_deposit: init_function_call
+                .map(|init_function_call| init_function_call.deposit),
             sign_option: Some(transfer_near_tokens_action.sign_option.into()),
         }
     }
@@ -56,13 +122,30 @@ impl TransferNEARTokensAction {
         >,
         context: crate::common::SignerContext,
     ) -> color_eyre::eyre::Result<Self> {
-        let amount: crate::common::TransferAmount = match optional_clap_variant
+        let cli_amount = optional_clap_variant
             .clone()
-            .and_then(|clap_variant| clap_variant.amount)
-        {
+            .and_then(|clap_variant| clap_variant.amount);
+        // In the interactive mode the user is asked whether to deploy a contract, otherwise
+        // the contract is deployed only if `--contract-file` is set
+        let is_interactive = cli_amount.is_none();
+        let amount: crate::common::TransferAmount = match cli_amount {
             Some(cli_amount) => crate::common::TransferAmount::from_unchecked(cli_amount),
             None => TransferNEARTokensAction::input_amount(&context)?,
         };
+        let contract = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.contract_file)
+        {
+            Some(cli_contract_file) => Some(SubAccountContract {
+                file_path: cli_contract_file,
+                init_function_call: Self::init_function_call_from_cli(
+                    optional_clap_variant.clone(),
+                    &context,
+                )?,
+            }),
+            None if is_interactive => Self::input_contract(&context)?,
+            None => None,
+        };
 
         let sign_option = match optional_clap_variant
             .clone()
@@ -74,9 +157,79 @@ impl TransferNEARTokensAction {
 
         Ok(Self {
             amount,
+            contract,
             sign_option,
         })
     }
+
+    fn init_function_call_from_cli(
+        optional_clap_variant: Option<CliTransferNEARTokensAction>,
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Option<InitFunctionCall>> {
+        let clap_variant = match optional_clap_variant {
+            Some(clap_variant) => clap_variant,
+            None => return Ok(None),
+        };
+        let method_name = match clap_variant.init_method_name {
+            Some(method_name) => method_name,
+            None => return Ok(None),
+        };
+        Ok(Some(InitFunctionCall {
+            method_name,
+            args: match clap_variant.init_args {
+                Some(args) => args,
+                None => CallFunctionAction::input_args(context)?,
+            },
+            gas: match clap_variant.init_prepaid_gas {
+                Some(gas) => gas,
+                None => CallFunctionAction::input_gas(context)?,
+            },
+            deposit: match clap_variant.init_attached_deposit {
+                Some(deposit) => deposit,
+                None => CallFunctionAction::input_deposit(context)?,
+            },
+        }))
+    }
+
+    fn input_contract(
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Option<SubAccountContract>> {
+        println!();
+        let choose_input = vec![
+            "No, I don't want to deploy a contract",
+            "Yes, I want to deploy a contract to the sub-account in the same transaction",
+        ];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to deploy a contract to the sub-account?")
+            .items(&choose_input)
+            .default(0)
+            .interact_on_opt(&Term::stderr())?;
+        if !matches!(select_choose_input, Some(1)) {
+            return Ok(None);
+        }
+        let input_file_path: String = Input::new()
+            .with_prompt("What is the file location of the contract?")
+            .interact_text()?;
+        let choose_input = vec!["Add an initialize", "Don't add an initialize"];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to initialize the contract in the same transaction?")
+            .items(&choose_input)
+            .default(0)
+            .interact_on_opt(&Term::stderr())?;
+        let init_function_call = match select_choose_input {
+            Some(0) => Some(InitFunctionCall {
+                method_name: CallFunctionAction::input_method_name(context)?,
+                args: CallFunctionAction::input_args(context)?,
+                gas: CallFunctionAction::input_gas(context)?,
+                deposit: CallFunctionAction::input_deposit(context)?,
+            }),
+            _ => None,
+        };
+        Ok(Some(SubAccountContract {
+            file_path: std::path::PathBuf::from(input_file_path).into(),
+            init_function_call,
+        }))
+    }
 }
 
 impl TransferNEARTokensAction {
@@ -150,6 +303,38 @@ impl TransferNEARTokensAction {
         );
         let mut actions = prepopulated_unsigned_transaction.actions.clone();
         actions.push(action);
+        // The contract is deployed after the transfer, so the sub-account has the balance to
+        // cover the storage of the code, and it never exists without the initialized contract.
+        if let Some(contract) = self.contract {
+            let code = std::fs::read(&contract.file_path.0).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to open or read the file: {:?}.\nError: {:?}",
+                    &contract.file_path.0, err
+                ))
+            })?;
+            crate::common::check_contract_code(
+                &code,
+                network_connection_config.as_ref(),
+                contract
+                    .init_function_call
+                    .as_ref()
+                    .map(|init_function_call| init_function_call.method_name.as_str()),
+            )
+            .await?;
+            actions.push(near_primitives::transaction::Action::DeployContract(
+                near_primitives::transaction::DeployContractAction { code },
+            ));
+            if let Some(init_function_call) = contract.init_function_call {
+                actions.push(near_primitives::transaction::Action::FunctionCall(
+                    near_primitives::transaction::FunctionCallAction {
+                        method_name: init_function_call.method_name,
+                        args: init_function_call.args.into_bytes(),
+                        gas: init_function_call.gas.inner,
+                        deposit: init_function_call.deposit.to_yoctonear(),
+                    },
+                ));
+            }
+        }
         let unsigned_transaction = near_primitives::transaction::Transaction {
             actions,
             ..prepopulated_unsigned_transaction
@@ -0,0 +1,41 @@
+use dialoguer::Input;
+
+use crate::types::utc_date_time::UtcDateTime;
+use near_primitives::types::{BlockId, BlockReference};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::BlockIdContext)]
+pub struct BlockIdDateTime {
+    date_time: UtcDateTime,
+}
+
+impl BlockIdDateTime {
+    pub fn input_date_time(
+        _context: &super::BlockIdContext,
+    ) -> color_eyre::eyre::Result<UtcDateTime> {
+        Ok(Input::new()
+            .with_prompt("Type the date and time in UTC (YYYY-MM-DD HH:MM:SS)")
+            .interact_text()?)
+    }
+
+    pub async fn to_block_reference(
+        &self,
+        connection_config: &crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<BlockReference> {
+        let block_view = crate::common::get_block_at_timestamp(
+            connection_config,
+            self.date_time.timestamp_nanosec,
+            None,
+        )
+        .await?;
+        println!(
+            "The last block at {} is #{} produced at {}",
+            self.date_time,
+            block_view.header.height,
+            UtcDateTime::from_timestamp_nanosec(block_view.header.timestamp_nanosec)
+        );
+        Ok(BlockReference::BlockId(BlockId::Hash(
+            block_view.header.hash,
+        )))
+    }
+}
@@ -0,0 +1,23 @@
+use dialoguer::Input;
+
+use near_primitives::types::{BlockId, BlockReference};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::BlockIdContext)]
+pub struct BlockIdHash {
+    block_id_hash: crate::types::crypto_hash::CryptoHash,
+}
+
+impl BlockIdHash {
+    pub fn input_block_id_hash(
+        _context: &super::BlockIdContext,
+    ) -> color_eyre::eyre::Result<crate::types::crypto_hash::CryptoHash> {
+        Ok(Input::new()
+            .with_prompt("Type the block ID hash")
+            .interact_text()?)
+    }
+
+    pub fn to_block_reference(&self) -> BlockReference {
+        BlockReference::BlockId(BlockId::Hash(self.block_id_hash.clone().into()))
+    }
+}
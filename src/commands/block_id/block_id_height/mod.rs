@@ -0,0 +1,23 @@
+use dialoguer::Input;
+
+use near_primitives::types::{BlockId, BlockReference};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::BlockIdContext)]
+pub struct BlockIdHeight {
+    block_id_height: near_primitives::types::BlockHeight,
+}
+
+impl BlockIdHeight {
+    pub fn input_block_id_height(
+        _context: &super::BlockIdContext,
+    ) -> color_eyre::eyre::Result<near_primitives::types::BlockHeight> {
+        Ok(Input::new()
+            .with_prompt("Type the block ID height")
+            .interact_text()?)
+    }
+
+    pub fn to_block_reference(&self) -> BlockReference {
+        BlockReference::BlockId(BlockId::Height(self.block_id_height))
+    }
+}
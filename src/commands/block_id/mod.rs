@@ -0,0 +1,56 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use near_primitives::types::{BlockReference, Finality};
+
+pub mod block_id_date_time;
+pub mod block_id_hash;
+pub mod block_id_height;
+
+/// The context of the block selector shared by the commands which view the state at a block.
+#[derive(Debug, Clone)]
+pub struct BlockIdContext;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = BlockIdContext)]
+///Choose Block ID
+pub enum BlockId {
+    #[strum_discriminants(strum(message = "View at final block"))]
+    /// Specify the final block
+    AtFinalBlock,
+    #[strum_discriminants(strum(message = "View at near-final (doomslug) block"))]
+    /// Specify the latest block which has a doomslug finality
+    AtDoomslugBlock,
+    #[strum_discriminants(strum(message = "View at optimistic (latest) block"))]
+    /// Specify the latest block which may not be final yet
+    AtOptimisticBlock,
+    #[strum_discriminants(strum(message = "View at block height"))]
+    /// Specify a block ID height
+    AtBlockHeight(self::block_id_height::BlockIdHeight),
+    #[strum_discriminants(strum(message = "View at block hash"))]
+    /// Specify a block ID hash
+    AtBlockHash(self::block_id_hash::BlockIdHash),
+    #[strum_discriminants(strum(message = "View at date and time"))]
+    /// Specify a date and time in UTC to view at the last block before it
+    AtDateTime(self::block_id_date_time::BlockIdDateTime),
+}
+
+impl BlockId {
+    pub async fn to_block_reference(
+        &self,
+        connection_config: &crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<BlockReference> {
+        Ok(match self {
+            Self::AtFinalBlock => Finality::Final.into(),
+            Self::AtDoomslugBlock => Finality::DoomSlug.into(),
+            Self::AtOptimisticBlock => Finality::None.into(),
+            Self::AtBlockHeight(block_id_height) => block_id_height.to_block_reference(),
+            Self::AtBlockHash(block_id_hash) => block_id_hash.to_block_reference(),
+            Self::AtDateTime(block_id_date_time) => {
+                block_id_date_time
+                    .to_block_reference(connection_config)
+                    .await?
+            }
+        })
+    }
+}
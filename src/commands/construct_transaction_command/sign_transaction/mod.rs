@@ -1,6 +1,8 @@
 use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::convert::TryFrom;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
+pub mod safety_policy;
 mod sign_manually;
 pub mod sign_with_keychain;
 #[cfg(feature = "ledger")]
@@ -66,6 +68,188 @@ impl SignTransaction {
     }
 }
 
+/// Checks the transaction against the current state of the network before it is signed, so that
+/// the mistakes are not found on-chain where the failed transaction still costs the fees.
+pub async fn check_transaction(
+    network_connection_config: &crate::common::ConnectionConfig,
+    transaction: &near_primitives::transaction::Transaction,
+    access_key_permission: &near_primitives::views::AccessKeyPermissionView,
+) -> crate::CliResult {
+    check_access_key_permission(transaction, access_key_permission)?;
+
+    let protocol_config =
+        serde_json::to_value(crate::common::get_protocol_config(network_connection_config).await?)?;
+    let max_total_prepaid_gas = protocol_config["runtime_config"]["wasm_config"]["limit_config"]
+        ["max_total_prepaid_gas"]
+        .as_u64()
+        .unwrap_or(u64::MAX);
+    // The gas values come from the user, so the sum is not expected to fit into u64.
+    let total_prepaid_gas: u128 = transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                u128::from(function_call_action.gas)
+            }
+            _ => 0,
+        })
+        .sum();
+    if total_prepaid_gas > u128::from(max_total_prepaid_gas) {
+        return Err(color_eyre::Report::msg(format!(
+            "The total prepaid gas of the transaction ({}) is over the limit of {}",
+            match u64::try_from(total_prepaid_gas) {
+                Ok(total_prepaid_gas) => crate::common::NearGas {
+                    inner: total_prepaid_gas
+                }
+                .to_string(),
+                Err(_) => format!("{} gas", total_prepaid_gas),
+            },
+            crate::common::NearGas {
+                inner: max_total_prepaid_gas
+            }
+        )));
+    }
+
+    check_receiver_exists(network_connection_config, transaction).await?;
+
+    let total_deposit: u128 = transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::Transfer(transfer_action) => {
+                transfer_action.deposit
+            }
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                function_call_action.deposit
+            }
+            _ => 0,
+        })
+        .sum();
+    let transaction_fee = crate::common::get_transaction_fee(
+        network_connection_config,
+        &transaction.signer_id,
+        &transaction.receiver_id,
+        &transaction.actions,
+    )
+    .await?;
+    let account_transfer_allowance = crate::common::get_account_transfer_allowance_with_fee(
+        network_connection_config,
+        transaction.signer_id.clone(),
+        transaction_fee.clone(),
+    )
+    .await?;
+    // The storage stake does not have to be kept by an account which deletes itself.
+    let is_signer_deleted = transaction.signer_id == transaction.receiver_id
+        && transaction.actions.iter().any(|action| {
+            matches!(
+                action,
+                near_primitives::transaction::Action::DeleteAccount(_)
+            )
+        });
+    let available_amount = if is_signer_deleted {
+        account_transfer_allowance
+            .account_liquid_balance()
+            .to_yoctonear()
+            .saturating_sub(transaction_fee.to_yoctonear())
+    } else {
+        account_transfer_allowance
+            .transfer_allowance()
+            .to_yoctonear()
+    };
+    if total_deposit > available_amount {
+        return Err(color_eyre::Report::msg(format!(
+            "The transaction needs {} of deposits and ~{} of fees, but{}",
+            crate::common::NearBalance::from_yoctonear(total_deposit),
+            transaction_fee,
+            account_transfer_allowance
+        )));
+    }
+    if let near_primitives::views::AccessKeyPermissionView::FunctionCall {
+        allowance: Some(allowance),
+        ..
+    } = access_key_permission
+    {
+        if transaction_fee.to_yoctonear() > *allowance {
+            return Err(color_eyre::Report::msg(format!(
+                "The allowance of the access key ({}) does not cover the fees of the transaction (~{})",
+                crate::common::NearBalance::from_yoctonear(*allowance),
+                transaction_fee
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The receiver must exist unless the transaction creates it or it is an implicit account.
+pub async fn check_receiver_exists(
+    network_connection_config: &crate::common::ConnectionConfig,
+    transaction: &near_primitives::transaction::Transaction,
+) -> crate::CliResult {
+    let is_receiver_created = matches!(
+        transaction.actions.first(),
+        Some(near_primitives::transaction::Action::CreateAccount(_))
+    );
+    if !is_receiver_created
+        && !crate::common::is_64_len_hex(&transaction.receiver_id)
+        && crate::common::get_account_view_if_exists(
+            network_connection_config,
+            transaction.receiver_id.clone(),
+        )
+        .await?
+        .is_none()
+    {
+        return Err(color_eyre::Report::msg(format!(
+            "The receiver account <{}> does not exist",
+            transaction.receiver_id
+        )));
+    }
+    Ok(())
+}
+
+/// A function call access key can only sign a single function call without a deposit
+/// to its receiver and, if the key has a list of methods, to one of them.
+pub fn check_access_key_permission(
+    transaction: &near_primitives::transaction::Transaction,
+    access_key_permission: &near_primitives::views::AccessKeyPermissionView,
+) -> crate::CliResult {
+    if let near_primitives::views::AccessKeyPermissionView::FunctionCall {
+        allowance: _,
+        receiver_id,
+        method_names,
+    } = access_key_permission
+    {
+        let function_call_action = match transaction.actions.as_slice() {
+            [near_primitives::transaction::Action::FunctionCall(function_call_action)] => {
+                function_call_action
+            }
+            _ => {
+                return Err(color_eyre::Report::msg(
+                    "The access key is a function call access key: it can only sign a transaction with a single function call".to_string(),
+                ))
+            }
+        };
+        if transaction.receiver_id.to_string() != *receiver_id {
+            return Err(color_eyre::Report::msg(format!(
+                "The access key can only be used for function calls to <{}>, not to <{}>",
+                receiver_id, transaction.receiver_id
+            )));
+        }
+        if !method_names.is_empty() && !method_names.contains(&function_call_action.method_name) {
+            return Err(color_eyre::Report::msg(format!(
+                "The access key can not call \"{}\", it can only call: {}",
+                function_call_action.method_name,
+                method_names.join(", ")
+            )));
+        }
+        if function_call_action.deposit > 0 {
+            return Err(color_eyre::Report::msg(
+                "A function call access key can not attach a deposit to the call".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn input_signer_public_key() -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
     Ok(Input::new()
         .with_prompt("Enter sender (signer) public key")
@@ -154,27 +338,11 @@ impl Submit {
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         match self {
             Submit::Send => {
-                println!("Transaction sent ...");
-                let json_rcp_client = near_jsonrpc_client::JsonRpcClient::connect(
-                    network_connection_config.rpc_url().as_str(),
-                );
-                let transaction_info = loop {
-                    let transaction_info_result = json_rcp_client
-                        .call(near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest{signed_transaction: signed_transaction.clone()})
-                        .await;
-                    match transaction_info_result {
-                        Ok(response) => {
-                            break response;
-                        }
-                        Err(err) => match crate::common::rpc_transaction_error(err) {
-                            Ok(_) => {
-                                actix::clock::sleep(std::time::Duration::from_millis(100)).await
-                            }
-                            Err(report) => return color_eyre::eyre::Result::Err(report),
-                        },
-                    };
-                };
-                Ok(Some(transaction_info))
+                self::safety_policy::SafetyPolicy::load(&network_connection_config)?
+                    .check(&signed_transaction.transaction)?;
+                Ok(Some(
+                    send_transaction(&network_connection_config, signed_transaction).await?,
+                ))
             }
             Submit::Display => {
                 println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
@@ -183,3 +351,145 @@ impl Submit {
         }
     }
 }
+
+/// Signs the transaction with the nonce and the block hash from the network after the same checks
+/// and the safety policy confirmation as `sign-with-private-key`, but does not send it, so that
+/// the caller knows whether a failure happened before the transaction was sent.
+pub async fn sign_online(
+    connection_config: &crate::common::ConnectionConfig,
+    unsigned_transaction: near_primitives::transaction::Transaction,
+    signer_private_key: &near_crypto::SecretKey,
+) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+    let online_signer_access_key_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: unsigned_transaction.signer_id.clone(),
+                    public_key: unsigned_transaction.public_key.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch public key information for nonce: {:?}",
+                    err
+                ))
+            })?;
+    let online_signer_access_key =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+            online_signer_access_key,
+        ) = online_signer_access_key_response.kind
+        {
+            online_signer_access_key
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+        };
+    let unsigned_transaction = near_primitives::transaction::Transaction {
+        block_hash: online_signer_access_key_response.block_hash,
+        nonce: online_signer_access_key.nonce + 1,
+        ..unsigned_transaction
+    };
+    check_transaction(
+        connection_config,
+        &unsigned_transaction,
+        &online_signer_access_key.permission,
+    )
+    .await?;
+    let signature = signer_private_key.sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+    let signed_transaction =
+        near_primitives::transaction::SignedTransaction::new(signature, unsigned_transaction);
+    println!("\nSigned transaction:\n");
+    crate::common::print_transaction(signed_transaction.transaction.clone());
+    self::safety_policy::SafetyPolicy::load(connection_config)?
+        .check(&signed_transaction.transaction)?;
+    Ok(signed_transaction)
+}
+
+/// Broadcasts the signed transaction and waits for its outcome. The safety policy is not checked
+/// here, the callers check it before.
+pub async fn send_transaction(
+    network_connection_config: &crate::common::ConnectionConfig,
+    signed_transaction: near_primitives::transaction::SignedTransaction,
+) -> color_eyre::eyre::Result<near_primitives::views::FinalExecutionOutcomeView> {
+    println!("Transaction sent ...");
+    let json_rcp_client =
+        near_jsonrpc_client::JsonRpcClient::connect(network_connection_config.rpc_url().as_str());
+    loop {
+        let transaction_info_result = json_rcp_client
+            .call(
+                near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                    signed_transaction: signed_transaction.clone(),
+                },
+            )
+            .await;
+        match transaction_info_result {
+            Ok(response) => return Ok(response),
+            Err(err) => match crate::common::rpc_transaction_error(err) {
+                Ok(_) => actix::clock::sleep(std::time::Duration::from_millis(100)).await,
+                Err(report) => return Err(report),
+            },
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_call_transaction(
+        receiver_id: &str,
+        method_name: &str,
+        deposit: u128,
+    ) -> near_primitives::transaction::Transaction {
+        near_primitives::transaction::Transaction {
+            signer_id: "alice.testnet".parse().unwrap(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: receiver_id.parse().unwrap(),
+            block_hash: Default::default(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_string(),
+                    args: vec![],
+                    gas: 30_000_000_000_000,
+                    deposit,
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn function_call_access_key_permission() {
+        let permission = near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "app.testnet".to_string(),
+            method_names: vec!["vote".to_string()],
+        };
+        assert!(check_access_key_permission(
+            &function_call_transaction("app.testnet", "vote", 0),
+            &permission
+        )
+        .is_ok());
+        assert!(check_access_key_permission(
+            &function_call_transaction("app.testnet", "withdraw", 0),
+            &permission
+        )
+        .is_err());
+        assert!(check_access_key_permission(
+            &function_call_transaction("other.testnet", "vote", 0),
+            &permission
+        )
+        .is_err());
+        assert!(check_access_key_permission(
+            &function_call_transaction("app.testnet", "vote", 1),
+            &permission
+        )
+        .is_err());
+        assert!(check_access_key_permission(
+            &function_call_transaction("other.testnet", "withdraw", 1),
+            &near_primitives::views::AccessKeyPermissionView::FullAccess
+        )
+        .is_ok());
+    }
+}
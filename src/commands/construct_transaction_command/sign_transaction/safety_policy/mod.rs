@@ -0,0 +1,347 @@
+use dialoguer::Input;
+use serde::Deserialize;
+
+use crate::common::NearBalance;
+
+/// The policy file as it is stored in `~/.near-credentials/<network>/safety-policy.json`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SafetyPolicyFile {
+    require_confirmation: Option<bool>,
+    max_transfer_amount: Option<String>,
+    max_deposit_amount: Option<String>,
+    receiver_allowlist: Vec<near_primitives::types::AccountId>,
+}
+
+/// The checks of a transaction right before it is sent to the network. Without a policy file,
+/// only the transactions on mainnet need a typed confirmation.
+#[derive(Debug, Default)]
+pub struct SafetyPolicy {
+    require_confirmation: bool,
+    max_transfer_amount: Option<NearBalance>,
+    max_deposit_amount: Option<NearBalance>,
+    receiver_allowlist: Vec<near_primitives::types::AccountId>,
+}
+
+impl SafetyPolicy {
+    pub fn load(
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+        path.push(network_connection_config.dir_name());
+        path.push(crate::consts::SAFETY_POLICY_FILE_NAME);
+        let policy_file = if path.exists() {
+            let data = std::fs::read_to_string(&path).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to read the safety policy: {}", err))
+            })?;
+            serde_json::from_str(&data).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The safety policy <{}> is invalid: {}",
+                    path.display(),
+                    err
+                ))
+            })?
+        } else {
+            SafetyPolicyFile::default()
+        };
+        let parse_amount = |amount: Option<String>| {
+            amount
+                .map(|amount| {
+                    amount.parse::<NearBalance>().map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "The safety policy <{}> is invalid: {}",
+                            path.display(),
+                            err
+                        ))
+                    })
+                })
+                .transpose()
+        };
+        Ok(Self {
+            require_confirmation: policy_file.require_confirmation.unwrap_or(matches!(
+                network_connection_config,
+                crate::common::ConnectionConfig::Mainnet
+            )),
+            max_transfer_amount: parse_amount(policy_file.max_transfer_amount)?,
+            max_deposit_amount: parse_amount(policy_file.max_deposit_amount)?,
+            receiver_allowlist: policy_file.receiver_allowlist,
+        })
+    }
+
+    /// Rejects the transaction if it breaks the limits of the policy and asks to type the receiver
+    /// account ID if the network requires a confirmation or the transaction is high risk.
+    pub fn check(
+        &self,
+        transaction: &near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.check_limits(transaction)?;
+        let high_risk_actions = high_risk_actions(transaction);
+        if !self.require_confirmation && high_risk_actions.is_empty() {
+            return Ok(());
+        }
+        println!(
+            "\nThe transaction from <{}> to <{}> transfers {} and attaches {} to function calls.",
+            transaction.signer_id,
+            transaction.receiver_id,
+            NearBalance::from_yoctonear(total_transfer_amount(transaction)),
+            NearBalance::from_yoctonear(total_deposit_amount(transaction))
+        );
+        for high_risk_action in &high_risk_actions {
+            println!("HIGH RISK: the transaction {}", high_risk_action);
+        }
+        let confirmation: String = Input::new()
+            .with_prompt(format!(
+                "Type the receiver account ID <{}> to send the transaction",
+                transaction.receiver_id
+            ))
+            .allow_empty(true)
+            .interact_text()?;
+        if confirmation.trim() != transaction.receiver_id.to_string() {
+            return Err(color_eyre::Report::msg(
+                "The transaction was not sent: the confirmation does not match the receiver"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Rejects the batch if any of its transactions breaks the limits of the policy.
+    pub fn check_batch_limits(
+        &self,
+        transactions: &[near_primitives::transaction::Transaction],
+    ) -> crate::CliResult {
+        for transaction in transactions {
+            self.check_limits(transaction)?;
+        }
+        Ok(())
+    }
+
+    /// Asks once for the whole batch to type the signer account ID if the network requires
+    /// a confirmation or any of the transactions is high risk.
+    pub fn confirm_batch(
+        &self,
+        transactions: &[near_primitives::transaction::Transaction],
+    ) -> crate::CliResult {
+        let signer_id = match transactions.first() {
+            Some(transaction) => &transaction.signer_id,
+            None => return Ok(()),
+        };
+        let high_risk_actions = transactions
+            .iter()
+            .flat_map(high_risk_actions)
+            .collect::<Vec<_>>();
+        if !self.require_confirmation && high_risk_actions.is_empty() {
+            return Ok(());
+        }
+        println!(
+            "\nThe batch of {} transactions from <{}> transfers {} and attaches {} to function calls.",
+            transactions.len(),
+            signer_id,
+            NearBalance::from_yoctonear(transactions.iter().map(total_transfer_amount).sum()),
+            NearBalance::from_yoctonear(transactions.iter().map(total_deposit_amount).sum())
+        );
+        for high_risk_action in &high_risk_actions {
+            println!("HIGH RISK: a transaction {}", high_risk_action);
+        }
+        let confirmation: String = Input::new()
+            .with_prompt(format!(
+                "Type the signer account ID <{}> to send the batch",
+                signer_id
+            ))
+            .allow_empty(true)
+            .interact_text()?;
+        if confirmation.trim() != signer_id.to_string() {
+            return Err(color_eyre::Report::msg(
+                "The batch was not sent: the confirmation does not match the signer".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_limits(
+        &self,
+        transaction: &near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        if !self.receiver_allowlist.is_empty()
+            && !self.receiver_allowlist.contains(&transaction.receiver_id)
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "The receiver <{}> is not in the allowlist of the safety policy",
+                transaction.receiver_id
+            )));
+        }
+        if let Some(max_transfer_amount) = &self.max_transfer_amount {
+            let transfer_amount = total_transfer_amount(transaction);
+            if transfer_amount > max_transfer_amount.to_yoctonear() {
+                return Err(color_eyre::Report::msg(format!(
+                    "The transaction transfers {}, but the safety policy allows at most {}",
+                    NearBalance::from_yoctonear(transfer_amount),
+                    max_transfer_amount
+                )));
+            }
+        }
+        if let Some(max_deposit_amount) = &self.max_deposit_amount {
+            let deposit_amount = total_deposit_amount(transaction);
+            if deposit_amount > max_deposit_amount.to_yoctonear() {
+                return Err(color_eyre::Report::msg(format!(
+                    "The transaction attaches {} to function calls, but the safety policy allows at most {}",
+                    NearBalance::from_yoctonear(deposit_amount),
+                    max_deposit_amount
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn total_transfer_amount(transaction: &near_primitives::transaction::Transaction) -> u128 {
+    transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::Transfer(transfer_action) => {
+                transfer_action.deposit
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+fn total_deposit_amount(transaction: &near_primitives::transaction::Transaction) -> u128 {
+    transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                function_call_action.deposit
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+fn high_risk_actions(transaction: &near_primitives::transaction::Transaction) -> Vec<String> {
+    transaction
+        .actions
+        .iter()
+        .filter_map(|action| match action {
+            near_primitives::transaction::Action::DeleteAccount(delete_account_action) => {
+                Some(format!(
+                    "deletes the account <{}> and sends its balance to <{}>",
+                    transaction.receiver_id, delete_account_action.beneficiary_id
+                ))
+            }
+            near_primitives::transaction::Action::AddKey(add_key_action)
+                if matches!(
+                    add_key_action.access_key.permission,
+                    near_primitives::account::AccessKeyPermission::FullAccess
+                ) =>
+            {
+                Some(format!(
+                    "adds the full access key {} to <{}>",
+                    add_key_action.public_key, transaction.receiver_id
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction(
+        actions: Vec<near_primitives::transaction::Action>,
+    ) -> near_primitives::transaction::Transaction {
+        near_primitives::transaction::Transaction {
+            signer_id: "alice.near".parse().unwrap(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: "bob.near".parse().unwrap(),
+            block_hash: Default::default(),
+            actions,
+        }
+    }
+
+    #[test]
+    fn safety_policy_limits() {
+        let policy = SafetyPolicy {
+            require_confirmation: true,
+            max_transfer_amount: Some("10 NEAR".parse().unwrap()),
+            max_deposit_amount: None,
+            receiver_allowlist: vec!["bob.near".parse().unwrap()],
+        };
+        let transfer = |amount: &str| {
+            near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction {
+                    deposit: amount.parse::<NearBalance>().unwrap().to_yoctonear(),
+                },
+            )
+        };
+        assert!(policy
+            .check_limits(&transaction(vec![transfer("10 NEAR")]))
+            .is_ok());
+        assert!(policy
+            .check_limits(&transaction(vec![transfer("6 NEAR"), transfer("6 NEAR")]))
+            .is_err());
+        let policy = SafetyPolicy {
+            receiver_allowlist: vec!["carol.near".parse().unwrap()],
+            ..policy
+        };
+        assert!(policy
+            .check_limits(&transaction(vec![transfer("1 NEAR")]))
+            .is_err());
+        let policy = SafetyPolicy {
+            receiver_allowlist: vec![],
+            ..policy
+        };
+        assert!(policy
+            .check_batch_limits(&[
+                transaction(vec![transfer("10 NEAR")]),
+                transaction(vec![transfer("1 NEAR")])
+            ])
+            .is_ok());
+        assert!(policy
+            .check_batch_limits(&[
+                transaction(vec![transfer("1 NEAR")]),
+                transaction(vec![transfer("11 NEAR")])
+            ])
+            .is_err());
+    }
+
+    #[test]
+    fn safety_policy_high_risk_actions() {
+        let delete_account = near_primitives::transaction::Action::DeleteAccount(
+            near_primitives::transaction::DeleteAccountAction {
+                beneficiary_id: "alice.near".parse().unwrap(),
+            },
+        );
+        let add_key = |permission| {
+            near_primitives::transaction::Action::AddKey(
+                near_primitives::transaction::AddKeyAction {
+                    public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                    access_key: near_primitives::account::AccessKey {
+                        nonce: 0,
+                        permission,
+                    },
+                },
+            )
+        };
+        assert_eq!(
+            high_risk_actions(&transaction(vec![
+                delete_account,
+                add_key(near_primitives::account::AccessKeyPermission::FullAccess),
+                add_key(near_primitives::account::AccessKeyPermission::FunctionCall(
+                    near_primitives::account::FunctionCallPermission {
+                        allowance: None,
+                        receiver_id: "bob.near".to_string(),
+                        method_names: vec![],
+                    }
+                )),
+            ]))
+            .len(),
+            2
+        );
+    }
+}
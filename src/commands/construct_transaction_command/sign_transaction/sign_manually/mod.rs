@@ -90,21 +90,28 @@ impl SignManually {
                             err
                         ))
                     })?;
-                let current_nonce =
+                let online_signer_access_key =
                     if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
                         online_signer_access_key,
                     ) = online_signer_access_key_response.kind
                     {
-                        online_signer_access_key.nonce
+                        online_signer_access_key
                     } else {
                         return Err(color_eyre::Report::msg(format!("Error current_nonce")));
                     };
-                near_primitives::transaction::Transaction {
+                let unsigned_transaction = near_primitives::transaction::Transaction {
                     public_key,
                     block_hash: online_signer_access_key_response.block_hash,
-                    nonce: current_nonce + 1,
+                    nonce: online_signer_access_key.nonce + 1,
                     ..prepopulated_unsigned_transaction
-                }
+                };
+                super::check_transaction(
+                    &network_connection_config,
+                    &unsigned_transaction,
+                    &online_signer_access_key.permission,
+                )
+                .await?;
+                unsigned_transaction
             }
         };
 
@@ -99,6 +99,7 @@ impl ContractFile {
                 err
             ))
         })?;
+        crate::common::check_contract_code(&code, network_connection_config.as_ref(), None).await?;
         let action = near_primitives::transaction::Action::DeployContract(
             near_primitives::transaction::DeployContractAction { code },
         );
@@ -1,39 +1,59 @@
-use dialoguer::Input;
+use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
+use std::str::FromStr;
 
 pub mod operation_mode;
 mod sender;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct DeleteAccountAction {
-    #[interactive_clap(skip_default_from_cli)]
     pub beneficiary_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    pub ft_contract_account_ids: crate::types::vec_string::VecString,
     #[interactive_clap(subcommand)]
     pub sign_option:
         crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
 }
 
 impl DeleteAccountAction {
-    fn from_cli_beneficiary_id(
-        optional_cli_sender_account_id: Option<crate::types::account_id::AccountId>,
-        context: &crate::common::SignerContext,
-    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
-        match optional_cli_sender_account_id {
-            Some(cli_beneficiary_id) => match &context.connection_config {
-                Some(network_connection_config) => match crate::common::get_account_state(
-                    &network_connection_config,
-                    cli_beneficiary_id.clone().into(),
-                )? {
-                    Some(_) => Ok(cli_beneficiary_id),
-                    None => {
-                        println!("Account <{}> doesn't exist", cli_beneficiary_id);
-                        Self::input_beneficiary_id(&context)
-                    }
-                },
-                None => Ok(cli_beneficiary_id),
-            },
-            None => Self::input_beneficiary_id(&context),
-        }
+    pub fn from_cli(
+        optional_clap_variant: Option<<DeleteAccountAction as interactive_clap::ToCli>::CliVariant>,
+        context: crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let cli_beneficiary_id = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.beneficiary_id);
+        // The token contracts are asked for only in interactive mode, so that scripts passing
+        // the beneficiary on the command line are not stopped by a prompt.
+        let is_interactive = cli_beneficiary_id.is_none();
+        let beneficiary_id = match cli_beneficiary_id {
+            Some(cli_beneficiary_id) => {
+                if Self::is_beneficiary_confirmed(&context, &cli_beneficiary_id)? {
+                    cli_beneficiary_id
+                } else {
+                    Self::input_beneficiary_id(&context)?
+                }
+            }
+            None => Self::input_beneficiary_id(&context)?,
+        };
+        let ft_contract_account_ids = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.ft_contract_account_ids)
+        {
+            Some(cli_ft_contract_account_ids) => cli_ft_contract_account_ids,
+            None if is_interactive => Self::input_ft_contract_account_ids(&context)?,
+            None => crate::types::vec_string::VecString(vec![]),
+        };
+        let sign_option = match optional_clap_variant.and_then(|clap_variant| clap_variant.sign_option) {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from_cli(Some(cli_sign_transaction), context)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_variant(context)?,
+        };
+        Ok(Self {
+            beneficiary_id,
+            ft_contract_account_ids,
+            sign_option,
+        })
     }
 
     pub fn input_beneficiary_id(
@@ -43,25 +63,101 @@ impl DeleteAccountAction {
             let account_id: crate::types::account_id::AccountId = Input::new()
                 .with_prompt("Enter the beneficiary ID to delete this account ID")
                 .interact_text()?;
-            if let Some(connection_config) = &context.connection_config {
-                if let Some(_) =
-                    crate::common::get_account_state(&connection_config, account_id.clone().into())?
-                {
-                    break Ok(account_id);
-                } else {
-                    println!("Account <{}> doesn't exist", account_id.to_string());
-                }
-            } else {
+            if Self::is_beneficiary_confirmed(context, &account_id)? {
                 break Ok(account_id);
             }
         }
     }
 
+    /// The balance of the deleted account is transferred to the beneficiary, and it is burnt if
+    /// the beneficiary does not exist (an implicit account is created by the transfer instead).
+    fn is_beneficiary_confirmed(
+        context: &crate::common::SignerContext,
+        beneficiary_id: &crate::types::account_id::AccountId,
+    ) -> color_eyre::eyre::Result<bool> {
+        if beneficiary_id.to_string() == context.signer_account_id.to_string() {
+            println!("The account can not be its own beneficiary, its balance would be burnt");
+            return Ok(false);
+        }
+        let connection_config = match &context.connection_config {
+            Some(connection_config) => connection_config,
+            None => return Ok(true),
+        };
+        if crate::common::is_64_len_hex(beneficiary_id.to_string())
+            || crate::common::get_account_state(connection_config, beneficiary_id.clone().into())?
+                .is_some()
+        {
+            return Ok(true);
+        }
+        println!(
+            "Account <{}> doesn't exist, the remaining balance of <{}> will be burnt",
+            beneficiary_id, context.signer_account_id
+        );
+        let choose_input = vec![
+            "No, I want to enter another beneficiary",
+            "Yes, I want to burn the remaining balance",
+        ];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to continue with this beneficiary?")
+            .items(&choose_input)
+            .default(0)
+            .interact_on_opt(&Term::stderr())?;
+        Ok(matches!(select_choose_input, Some(1)))
+    }
+
+    pub fn input_ft_contract_account_ids(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::vec_string::VecString> {
+        println!();
+        let choose_input = vec![
+            "Yes, I want to check the fungible token balances of this account",
+            "No, I don't want to check the fungible token balances",
+        ];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("The fungible tokens of the deleted account are lost. Do you want to check its balances for some token contracts?")
+            .items(&choose_input)
+            .default(0)
+            .interact_on_opt(&Term::stderr())?;
+        match select_choose_input {
+            Some(0) => {
+                let input_ft_contract_account_ids: String = Input::new()
+                    .with_prompt("Enter a comma-separated list of token contracts (example: usdc.fakes.testnet, wrap.testnet)")
+                    .interact_text()?;
+                crate::types::vec_string::VecString::from_str(&input_ft_contract_account_ids)
+            }
+            Some(1) => Ok(crate::types::vec_string::VecString(vec![])),
+            _ => unreachable!("Error"),
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        let account_id = prepopulated_unsigned_transaction.signer_id.clone();
+        if let Some(connection_config) = &network_connection_config {
+            print_account_warnings(
+                connection_config,
+                &account_id,
+                &self.ft_contract_account_ids,
+            )
+            .await?;
+        }
+        let typed_account_id: String = Input::new()
+            .with_prompt(format!(
+                "The deletion can not be undone. Type <{}> to confirm",
+                account_id
+            ))
+            .allow_empty(true)
+            .interact_text()?;
+        if typed_account_id.trim() != account_id.to_string() {
+            return Err(color_eyre::Report::msg(format!(
+                "The account <{}> is not deleted: the confirmation does not match",
+                account_id
+            )));
+        }
+
         let beneficiary_id: near_primitives::types::AccountId = self.beneficiary_id.clone().into();
         let action = near_primitives::transaction::Action::DeleteAccount(
             near_primitives::transaction::DeleteAccountAction { beneficiary_id },
@@ -78,13 +174,146 @@ impl DeleteAccountAction {
             .await?
         {
             Some(transaction_info) => {
+                let is_successful = !matches!(
+                    transaction_info.status,
+                    near_primitives::views::FinalExecutionStatus::Failure(_)
+                );
                 crate::common::print_transaction_status(
                     transaction_info,
-                    network_connection_config,
+                    network_connection_config.clone(),
                 );
+                if let (true, Some(connection_config)) = (is_successful, &network_connection_config)
+                {
+                    remove_key_files(connection_config, &account_id)?;
+                }
             }
             None => {}
         };
         Ok(())
     }
 }
+
+/// Warns about what is lost with the account: the deployed contract and the fungible tokens of
+/// the given contracts. An account with a staked balance can not be deleted at all.
+async fn print_account_warnings(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+    ft_contract_account_ids: &crate::types::vec_string::VecString,
+) -> crate::CliResult {
+    let account_view =
+        match crate::common::get_account_view_if_exists(connection_config, account_id.clone())
+            .await?
+        {
+            Some(account_view) => account_view,
+            None => return Ok(()),
+        };
+    println!();
+    if account_view.code_hash != near_primitives::hash::CryptoHash::default() {
+        println!(
+            "Warning: <{}> has a deployed contract (code hash: {}), it will be deleted with the account",
+            account_id, account_view.code_hash
+        );
+    }
+    // The network rejects the deletion of an account with a staked balance
+    // (`DeleteAccountStaking`), the transaction would only burn its fee.
+    if account_view.locked > 0 {
+        return Err(color_eyre::Report::msg(format!(
+            "The account <{}> can not be deleted: it has {} staked, unstake it first",
+            account_id,
+            crate::common::NearBalance::from_yoctonear(account_view.locked)
+        )));
+    }
+    for ft_contract_account_id in ft_contract_account_ids
+        .0
+        .iter()
+        .filter(|ft_contract_account_id| !ft_contract_account_id.is_empty())
+    {
+        let ft_contract_account_id: near_primitives::types::AccountId =
+            ft_contract_account_id.parse().map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Invalid token contract <{}>: {}",
+                    ft_contract_account_id, err
+                ))
+            })?;
+        let ft_metadata =
+            crate::common::get_ft_metadata(connection_config, ft_contract_account_id.clone())
+                .await?;
+        let amount = crate::common::get_ft_balance(
+            connection_config,
+            ft_contract_account_id.clone(),
+            account_id.clone(),
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        if amount > 0 {
+            println!(
+                "Warning: <{}> holds {} on <{}>, the tokens will be lost",
+                account_id,
+                ft_metadata.balance(amount),
+                ft_contract_account_id
+            );
+        } else {
+            println!(
+                "<{}> holds no {} on <{}>",
+                account_id, ft_metadata.symbol, ft_contract_account_id
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Offers to remove the keys of the deleted account from the keychain: the legacy
+/// `<account_id>.json` file and the `<account_id>` directory.
+fn remove_key_files(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> crate::CliResult {
+    let mut keychain_path = dirs::home_dir().expect("Impossible to get your home dir!");
+    keychain_path.push(connection_config.dir_name());
+    let key_file_path = keychain_path.join(format!("{}.json", account_id));
+    let account_dir_path = keychain_path.join(account_id.to_string());
+    if !key_file_path.is_file() && !account_dir_path.is_dir() {
+        return Ok(());
+    }
+    println!();
+    let choose_input = vec![
+        "Yes, I want to remove the keys of the deleted account",
+        "No, I want to keep the keys",
+    ];
+    let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!(
+            "Do you want to remove the keys of <{}> from the keychain (~/{})?",
+            account_id,
+            connection_config.dir_name()
+        ))
+        .items(&choose_input)
+        .default(0)
+        .interact_on_opt(&Term::stderr())?;
+    if !matches!(select_choose_input, Some(0)) {
+        return Ok(());
+    }
+    if key_file_path.is_file() {
+        std::fs::remove_file(&key_file_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to remove the access key file {}: {}",
+                key_file_path.display(),
+                err
+            ))
+        })?;
+        println!("The access key file {} is removed", key_file_path.display());
+    }
+    if account_dir_path.is_dir() {
+        std::fs::remove_dir_all(&account_dir_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to remove the access key directory {}: {}",
+                account_dir_path.display(),
+                err
+            ))
+        })?;
+        println!(
+            "The access key directory {} is removed",
+            account_dir_path.display()
+        );
+    }
+    Ok(())
+}
@@ -1,12 +1,30 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext)]
+#[interactive_clap(output_context = crate::commands::block_id::BlockIdContext)]
 pub struct CallFunctionView {
     method_name: String,
     function_args: String,
     #[interactive_clap(subcommand)]
-    selected_block_id: super::block_id::BlockId,
+    selected_block_id: crate::commands::block_id::BlockId,
+}
+
+struct CallFunctionViewContext;
+
+impl CallFunctionViewContext {
+    pub fn from_previous_context(
+        _previous_context: super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext,
+        _scope: &<CallFunctionView as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self
+    }
+}
+
+impl From<CallFunctionViewContext> for crate::commands::block_id::BlockIdContext {
+    fn from(_item: CallFunctionViewContext) -> Self {
+        Self
+    }
 }
 
 impl CallFunctionView {
@@ -33,13 +51,40 @@ impl CallFunctionView {
         network_connection_config: crate::common::ConnectionConfig,
         contract_account_id: near_primitives::types::AccountId,
     ) -> crate::CliResult {
-        self.selected_block_id
-            .process(
-                contract_account_id,
-                network_connection_config,
-                self.method_name,
-                self.function_args.into_bytes(),
-            )
-            .await
+        println!();
+        let block_reference = self
+            .selected_block_id
+            .to_block_reference(&network_connection_config)
+            .await?;
+        let query_view_method_response = near_jsonrpc_client::JsonRpcClient::connect(
+            &network_connection_config.rpc_url().as_str(),
+        )
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: contract_account_id,
+                method_name: self.method_name,
+                args: near_primitives::types::FunctionArgs::from(self.function_args.into_bytes()),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch query for view method: {:?}", err))
+        })?;
+        let call_result =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
+                query_view_method_response.kind
+            {
+                result.result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let call_result_str = String::from_utf8(call_result)?;
+        let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
+            .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
+        println!("--------------");
+        println!();
+        println!("{}", serde_json::to_string_pretty(&serde_call_result)?);
+        Ok(())
     }
 }
@@ -1,4 +1,3 @@
-mod block_id;
 mod call_function_type;
 mod contract;
 pub mod operation_mode;
@@ -0,0 +1,295 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext)]
+pub struct Claim {
+    linkdrop_key: crate::types::secret_key::SecretKey,
+    #[interactive_clap(subcommand)]
+    claim_account: ClaimAccount,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext)]
+///Which account gets the linkdrop?
+pub enum ClaimAccount {
+    #[strum_discriminants(strum(message = "An existing account"))]
+    /// Claim the linkdrop into an existing account (claim)
+    ExistingAccount(ExistingAccount),
+    #[strum_discriminants(strum(message = "A new account created with the linkdrop"))]
+    /// Create a new account with a new key and claim the linkdrop into it (create_account_and_claim)
+    NewAccount(NewAccount),
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext)]
+pub struct ExistingAccount {
+    #[interactive_clap(skip_default_from_cli)]
+    account_id: crate::types::account_id::AccountId,
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext)]
+pub struct NewAccount {
+    #[interactive_clap(skip_default_from_cli)]
+    new_account_id: crate::types::account_id::AccountId,
+}
+
+impl Claim {
+    fn input_linkdrop_key(
+        _context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::secret_key::SecretKey> {
+        Ok(Input::new()
+            .with_prompt("Enter the linkdrop key (the last part of the claim link)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let linkdrop_contract_account_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let linkdrop_private_key: near_crypto::SecretKey = self.linkdrop_key.into();
+        let linkdrop_public_key = linkdrop_private_key.public_key();
+        // The `send` method adds the linkdrop key to the contract, and claiming deletes it.
+        if near_jsonrpc_client::JsonRpcClient::connect(network_connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: linkdrop_contract_account_id.clone(),
+                    public_key: linkdrop_public_key.clone(),
+                },
+            })
+            .await
+            .is_err()
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "The linkdrop with the key {} is not found on <{}>, it may have been claimed already",
+                linkdrop_public_key, linkdrop_contract_account_id
+            )));
+        }
+
+        let (action, new_account_id, new_key_file_paths) = match self.claim_account {
+            ClaimAccount::ExistingAccount(existing_account) => (
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: "claim".to_string(),
+                    args: serde_json::json!({
+                        "account_id": existing_account.account_id.to_string(),
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    gas: super::LINKDROP_CLAIM_GAS,
+                    deposit: 0,
+                },
+                None,
+                vec![],
+            ),
+            ClaimAccount::NewAccount(new_account) => {
+                let new_account_id: near_primitives::types::AccountId =
+                    new_account.new_account_id.into();
+                let key_pair_properties = crate::common::generate_keypair().await?;
+                let new_key_file_paths = crate::common::save_access_key_to_keychain(
+                    Some(network_connection_config.clone()),
+                    key_pair_properties.clone(),
+                    &new_account_id.to_string(),
+                )
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to save a file with access key: {}",
+                        err
+                    ))
+                })?;
+                (
+                    near_primitives::transaction::FunctionCallAction {
+                        method_name: "create_account_and_claim".to_string(),
+                        args: serde_json::json!({
+                            "new_account_id": new_account_id.to_string(),
+                            "new_public_key": key_pair_properties.public_key_str,
+                        })
+                        .to_string()
+                        .into_bytes(),
+                        gas: super::LINKDROP_CLAIM_GAS,
+                        deposit: 0,
+                    },
+                    Some(new_account_id),
+                    new_key_file_paths,
+                )
+            }
+        };
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: linkdrop_contract_account_id,
+            public_key: linkdrop_public_key.clone(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(action)],
+            ..prepopulated_unsigned_transaction
+        };
+        // Until the transaction is sent, the new account is surely not created, so the files of
+        // its key are removed if the checks fail or the user declines the transaction.
+        let signed_transaction =
+            match crate::commands::construct_transaction_command::sign_transaction::sign_online(
+                &network_connection_config,
+                unsigned_transaction,
+                &linkdrop_private_key,
+            )
+            .await
+            {
+                Ok(signed_transaction) => signed_transaction,
+                Err(err) => {
+                    crate::common::remove_new_account_key_files(&new_key_file_paths);
+                    return Err(err);
+                }
+            };
+        // If the outcome is unknown, the new account may already be created with the key.
+        let transaction_info =
+            match crate::commands::construct_transaction_command::sign_transaction::send_transaction(
+                &network_connection_config,
+                signed_transaction,
+            )
+            .await
+            {
+                Ok(transaction_info) => transaction_info,
+                Err(err) => {
+                    for new_key_file_path in &new_key_file_paths {
+                        println!(
+                            "The outcome of the transaction is unknown, the new key file {} is kept",
+                            new_key_file_path.display()
+                        );
+                    }
+                    return Err(err);
+                }
+            };
+        // `create_account_and_claim` returns `false` and keeps the linkdrop when it fails to
+        // create the account, the transaction itself succeeds.
+        let is_account_created = match &transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::SuccessValue(value) => {
+                base64::decode(value).map_or(true, |value| value != b"false")
+            }
+            near_primitives::views::FinalExecutionStatus::Failure(_) => false,
+            _ => true,
+        };
+        let is_successful = !matches!(
+            transaction_info.status,
+            near_primitives::views::FinalExecutionStatus::Failure(_)
+        );
+        crate::common::print_transaction_status(transaction_info, Some(network_connection_config));
+        if let Some(new_account_id) = new_account_id {
+            if is_account_created {
+                println!(
+                    "Account <{}> has been created with the linkdrop.",
+                    new_account_id
+                );
+            } else {
+                if is_successful {
+                    println!(
+                        "Account <{}> has not been created (it may already exist), the linkdrop can be claimed again.",
+                        new_account_id
+                    );
+                }
+                crate::common::remove_new_account_key_files(&new_key_file_paths);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ExistingAccount {
+    fn from_cli_account_id(
+        optional_cli_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_account_id {
+            Some(cli_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_account_id);
+                    Self::input_account_id(&context)
+                }
+            },
+            None => Self::input_account_id(&context),
+        }
+    }
+
+    fn input_account_id(
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("Which account do you want to claim the linkdrop into?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+}
+
+impl NewAccount {
+    fn from_cli_new_account_id(
+        optional_cli_new_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_new_account_id {
+            Some(cli_new_account_id) => {
+                if Self::is_new_account_id_valid(context, &cli_new_account_id)? {
+                    Ok(cli_new_account_id)
+                } else {
+                    Self::input_new_account_id(context)
+                }
+            }
+            None => Self::input_new_account_id(context),
+        }
+    }
+
+    fn input_new_account_id(
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the new account ID? (example: alice.testnet)")
+                .interact_text()?;
+            if Self::is_new_account_id_valid(context, &account_id)? {
+                break Ok(account_id);
+            }
+        }
+    }
+
+    /// The linkdrop contract can only create its direct sub-accounts (`<name>.<linkdrop contract>`).
+    fn is_new_account_id_valid(
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+        new_account_id: &crate::types::account_id::AccountId,
+    ) -> color_eyre::eyre::Result<bool> {
+        let linkdrop_contract_account_id =
+            super::get_linkdrop_contract_account_id(&context.connection_config)?;
+        let is_linkdrop_sub_account = new_account_id
+            .to_string()
+            .strip_suffix(&format!(".{}", linkdrop_contract_account_id))
+            .map_or(false, |name| !name.contains('.'));
+        if !is_linkdrop_sub_account {
+            println!(
+                "The linkdrop can only create <name.{}> accounts",
+                linkdrop_contract_account_id
+            );
+            return Ok(false);
+        }
+        if crate::common::get_account_state(
+            &context.connection_config,
+            new_account_id.clone().into(),
+        )?
+        .is_some()
+        {
+            println!("Account <{}> already exists", new_account_id);
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,64 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod claim;
+pub mod operation_mode;
+mod send;
+
+const LINKDROP_SEND_GAS: u64 = 30_000_000_000_000; // 30 TeraGas
+const LINKDROP_CLAIM_GAS: u64 = 100_000_000_000_000; // 100 TeraGas
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = self::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext)]
+///What do you want to do with a linkdrop?
+pub enum LinkdropAction {
+    #[strum_discriminants(strum(message = "Create a linkdrop and get a claim link"))]
+    /// Create a linkdrop with a new key and get a claim link (send)
+    Send(self::send::Sender),
+    #[strum_discriminants(strum(message = "Claim a linkdrop with its key"))]
+    /// Claim a linkdrop into an existing or a new account (claim, create_account_and_claim)
+    Claim(self::claim::Claim),
+}
+
+impl LinkdropAction {
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: "test".parse().unwrap(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: get_linkdrop_contract_account_id(&network_connection_config)?,
+            block_hash: Default::default(),
+            actions: vec![],
+        };
+        match self {
+            Self::Send(sender) => {
+                sender
+                    .process(unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Self::Claim(claim) => {
+                claim
+                    .process(unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// The linkdrop contract is deployed on the top-level account of the network.
+fn get_linkdrop_contract_account_id(
+    network_connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    match network_connection_config {
+        crate::common::ConnectionConfig::Testnet => Ok("testnet".parse()?),
+        crate::common::ConnectionConfig::Mainnet => Ok("near".parse()?),
+        crate::common::ConnectionConfig::Betanet => Ok("betanet".parse()?),
+        crate::common::ConnectionConfig::Custom { url } => Err(color_eyre::Report::msg(format!(
+            "The linkdrop contract is not known for the custom network {}",
+            url
+        ))),
+    }
+}
@@ -0,0 +1,171 @@
+use dialoguer::Input;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext)]
+#[interactive_clap(output_context = crate::common::SignerContext)]
+pub struct Sender {
+    #[interactive_clap(skip_default_from_cli)]
+    pub sender_account_id: crate::types::account_id::AccountId,
+    pub amount: crate::common::NearBalance,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+struct SenderContext {
+    connection_config: crate::common::ConnectionConfig,
+    sender_account_id: crate::types::account_id::AccountId,
+}
+
+impl SenderContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+        scope: &<Sender as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            sender_account_id: scope.sender_account_id.clone(),
+        }
+    }
+}
+
+impl From<SenderContext> for crate::common::SignerContext {
+    fn from(item: SenderContext) -> Self {
+        Self {
+            connection_config: Some(item.connection_config),
+            signer_account_id: item.sender_account_id,
+        }
+    }
+}
+
+impl Sender {
+    fn from_cli_sender_account_id(
+        optional_cli_sender_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_sender_account_id {
+            Some(cli_sender_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_sender_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_sender_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_sender_account_id);
+                    Sender::input_sender_account_id(&context)
+                }
+            },
+            None => Self::input_sender_account_id(&context),
+        }
+    }
+
+    fn input_sender_account_id(
+        context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("Which account pays for the linkdrop?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+
+    fn input_amount(
+        _context: &super::operation_mode::online_mode::select_server::LinkdropCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
+            .with_prompt("How many NEAR Tokens do you want to put in the linkdrop? (example: 10NEAR or 1.5near)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let linkdrop_contract_account_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let key_pair_properties = crate::common::generate_keypair().await?;
+        // The key is saved before the transaction is signed: if the outcome of the transaction
+        // is lost, the linkdrop can still be claimed with the key from the keychain.
+        crate::common::save_access_key_to_account_dir(
+            Some(network_connection_config.clone()),
+            key_pair_properties.clone(),
+            &linkdrop_contract_account_id.to_string(),
+        )
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })?;
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.sender_account_id.clone().into(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: "send".to_string(),
+                    args: serde_json::json!({
+                        "public_key": key_pair_properties.public_key_str,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    gas: super::LINKDROP_SEND_GAS,
+                    deposit: self.amount.to_yoctonear(),
+                },
+            )],
+            ..prepopulated_unsigned_transaction
+        };
+        let transaction_info = match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => transaction_info,
+            None => return Ok(()),
+        };
+        let is_successful = !matches!(
+            transaction_info.status,
+            near_primitives::views::FinalExecutionStatus::Failure(_)
+        );
+        crate::common::print_transaction_status(
+            transaction_info,
+            Some(network_connection_config.clone()),
+        );
+        if is_successful {
+            let secret_key =
+                near_crypto::SecretKey::from_str(&key_pair_properties.secret_keypair_str)?;
+            println!(
+                "The linkdrop of {} has been created.\nLinkdrop key: {}\nClaim link: {}",
+                self.amount,
+                secret_key,
+                get_claim_link(
+                    &network_connection_config,
+                    &linkdrop_contract_account_id,
+                    &secret_key
+                )?
+            );
+            println!("Anyone with the link or the key can claim the linkdrop, keep them secret until you hand them over.");
+        }
+        Ok(())
+    }
+}
+
+/// The wallet page that claims the linkdrop with its key into a new or an existing account.
+fn get_claim_link(
+    network_connection_config: &crate::common::ConnectionConfig,
+    linkdrop_contract_account_id: &near_primitives::types::AccountId,
+    secret_key: &near_crypto::SecretKey,
+) -> color_eyre::eyre::Result<url::Url> {
+    let secret_key = secret_key.to_string();
+    Ok(network_connection_config.wallet_url().join(&format!(
+        "linkdrop/{}/{}",
+        linkdrop_contract_account_id,
+        secret_key.trim_start_matches("ed25519:")
+    ))?)
+}
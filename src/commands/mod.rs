@@ -1,11 +1,16 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 pub mod add_command;
+pub mod block_id;
 pub mod construct_transaction_command;
 pub mod delete_command;
 pub mod execute_command;
 pub mod generate_shell_completions_command;
+pub mod linkdrop_command;
 pub mod login;
+pub mod rotate_key_command;
+pub mod staking_command;
+pub mod tokens_command;
 pub mod transfer_command;
 pub mod utils_command;
 pub mod view_command;
@@ -29,6 +34,12 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "Execute function (contract method)"))]
     ///Execute function (contract method)
     Execute(self::execute_command::OptionMethod),
+    #[strum_discriminants(strum(message = "Delegate tokens to staking pools"))]
+    ///Use these to stake, unstake and withdraw tokens in staking pools
+    Staking(self::staking_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View and transfer fungible and non-fungible tokens"))]
+    ///Use these to view and transfer fungible and non-fungible tokens
+    Tokens(self::tokens_command::TokensCommand),
     #[strum_discriminants(strum(
         message = "Add access key, contract code, stake proposal, sub-account, implicit-account"
     ))]
@@ -37,6 +48,12 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "Delete access key, account"))]
     ///Use these to delete access key, sub-account
     Delete(self::delete_command::DeleteAction),
+    #[strum_discriminants(strum(message = "Create and claim linkdrops"))]
+    ///Use these to create a linkdrop with a claim link and to claim linkdrops
+    Linkdrop(self::linkdrop_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Rotate an access key"))]
+    ///Replace an access key with a new one with the same permissions in one transaction
+    RotateKey(self::rotate_key_command::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Construct a new transaction"))]
     ///Prepare and, optionally, submit a new transaction
     ConstructTransaction(self::construct_transaction_command::operation_mode::OperationMode),
@@ -46,7 +63,7 @@ pub enum TopLevelCommand {
 }
 
 impl TopLevelCommand {
-    pub async fn process(self) -> crate::CliResult {
+    pub async fn process(self, output_format: crate::common::OutputFormat) -> crate::CliResult {
         let unsigned_transaction = near_primitives::transaction::Transaction {
             signer_id: "test".parse().unwrap(),
             public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
@@ -60,10 +77,14 @@ impl TopLevelCommand {
             Self::ConstructTransaction(mode) => mode.process(unsigned_transaction).await,
             Self::Delete(delete_action) => delete_action.process(unsigned_transaction).await,
             Self::Execute(option_method) => option_method.process(unsigned_transaction).await,
+            Self::Linkdrop(operation_mode) => operation_mode.process().await,
             Self::Login(mode) => mode.process().await,
+            Self::RotateKey(operation_mode) => operation_mode.process().await,
+            Self::Staking(operation_mode) => operation_mode.process(unsigned_transaction).await,
+            Self::Tokens(tokens_command) => tokens_command.process(unsigned_transaction).await,
             Self::Transfer(currency) => currency.process(unsigned_transaction).await,
             Self::Utils(util_type) => util_type.process().await,
-            Self::View(view_query_request) => view_query_request.process().await,
+            Self::View(view_query_request) => view_query_request.process(output_format).await,
         }
     }
 }
@@ -0,0 +1,333 @@
+use dialoguer::Input;
+use std::str::FromStr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::RotateKeyCommandNetworkContext)]
+pub struct Account {
+    account_id: crate::types::account_id::AccountId,
+    old_public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(subcommand)]
+    new_key: NewKey,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::RotateKeyCommandNetworkContext)]
+///Where does the new access key come from?
+pub enum NewKey {
+    #[strum_discriminants(strum(message = "Generate a new key pair and save it in the keychain"))]
+    /// Generate a new key pair and save it in the keychain
+    GenerateKeypair,
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(message = "Use the public key of a Ledger Nano S/X device"))]
+    /// Use the public key of a Ledger Nano S/X device
+    Ledger(LedgerPublicKey),
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::RotateKeyCommandNetworkContext)]
+pub struct LedgerPublicKey {
+    #[interactive_clap(long)]
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+}
+
+#[cfg(feature = "ledger")]
+impl LedgerPublicKey {
+    fn input_seed_phrase_hd_path(
+        _context: &super::operation_mode::online_mode::select_server::RotateKeyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::slip10::BIP32Path> {
+        Ok(Input::new()
+            .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
+            .with_initial_text("44'/397'/0'/0'/1'")
+            .interact_text()?)
+    }
+
+    async fn get_public_key(self) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        println!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            self.seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(self.seed_phrase_hd_path.into())
+            .await
+            .map_err(|near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            })?;
+        Ok(near_crypto::PublicKey::ED25519(
+            near_crypto::ED25519PublicKey::from(public_key.to_bytes()),
+        ))
+    }
+}
+
+impl Account {
+    fn input_account_id(
+        _context: &super::operation_mode::online_mode::select_server::RotateKeyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        Ok(Input::new()
+            .with_prompt("Which account do you want to rotate the access key for?")
+            .interact_text()?)
+    }
+
+    fn input_old_public_key(
+        _context: &super::operation_mode::online_mode::select_server::RotateKeyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
+        Ok(Input::new()
+            .with_prompt("Enter the public key of the access key to replace")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let account_id: near_primitives::types::AccountId = self.account_id.clone().into();
+        let old_public_key = self.old_public_key.0.clone();
+
+        let query_view_method_response =
+            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+                .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                    block_reference: near_primitives::types::Finality::Final.into(),
+                    request: near_primitives::views::QueryRequest::ViewAccessKey {
+                        account_id: account_id.clone(),
+                        public_key: old_public_key.clone(),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "The access key {} of <{}> is not found on chain: {:?}",
+                        old_public_key, account_id, err
+                    ))
+                })?;
+        let old_access_key =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+                access_key_view,
+            ) = query_view_method_response.kind
+            {
+                access_key_view
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let permission = match old_access_key.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => {
+                near_primitives::account::AccessKeyPermission::FullAccess
+            }
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id,
+                method_names,
+            } => near_primitives::account::AccessKeyPermission::FunctionCall(
+                near_primitives::account::FunctionCallPermission {
+                    allowance,
+                    receiver_id,
+                    method_names,
+                },
+            ),
+        };
+
+        let old_key_file_paths = find_key_files(&connection_config, &account_id, &old_public_key)?;
+        // A function call access key can not add and delete keys, so its rotation is signed
+        // with a full access key from the keychain. The signer key is looked up before the new
+        // key pair is generated, so nothing is saved if the rotation can not be signed.
+        let signer_key = if let near_primitives::account::AccessKeyPermission::FullAccess =
+            permission
+        {
+            match old_key_file_paths.first() {
+                Some(file_path) => crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::read_access_key_file(file_path)?,
+                None => {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The private key of {} for <{}> is not found in the keychain (~/{}). The rotation of a full access key is signed with the old key from the keychain, so a key held on a Ledger device or known only as a private key can not be rotated with this command; add the new key and delete the old one with `add` and `delete` instead",
+                        old_public_key,
+                        account_id,
+                        connection_config.dir_name()
+                    )))
+                }
+            }
+        } else {
+            println!("The old key is a function call access key, so the transaction is signed with a full access key from the keychain.");
+            crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::read_access_key_file(
+                &crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::get_access_key_file_path(
+                    &account_id,
+                    &connection_config,
+                )
+                .await?,
+            )?
+        };
+
+        let mut keychain_path = dirs::home_dir().expect("Impossible to get your home dir!");
+        keychain_path.push(connection_config.dir_name());
+        let legacy_key_file_path = keychain_path.join(format!("{}.json", account_id));
+        // The legacy `<account_id>.json` file is used for signing with a full access key, so it
+        // never gets a function call access key.
+        let is_full_access = matches!(
+            permission,
+            near_primitives::account::AccessKeyPermission::FullAccess
+        );
+        // The new key is saved before the transaction is sent, so that it is not lost even if
+        // the old key is already deleted on chain when something goes wrong afterwards.
+        let mut new_key_file_paths = vec![];
+        let new_public_key = match self.new_key {
+            NewKey::GenerateKeypair => {
+                let key_pair_properties = crate::common::generate_keypair().await?;
+                new_key_file_paths.push(
+                    crate::common::save_access_key_to_account_dir(
+                        Some(connection_config.clone()),
+                        key_pair_properties.clone(),
+                        &account_id.to_string(),
+                    )
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to save a file with access key: {}",
+                            err
+                        ))
+                    })?
+                    .0,
+                );
+                near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?
+            }
+            #[cfg(feature = "ledger")]
+            NewKey::Ledger(ledger_public_key) => ledger_public_key.get_public_key().await?,
+        };
+        if new_public_key == old_public_key {
+            remove_new_key_files(&new_key_file_paths);
+            return Err(color_eyre::Report::msg(
+                "The new access key is the same as the old one".to_string(),
+            ));
+        }
+
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: account_id.clone(),
+            public_key: signer_key.public_key.clone(),
+            nonce: 0,
+            receiver_id: account_id.clone(),
+            block_hash: Default::default(),
+            actions: vec![
+                near_primitives::transaction::Action::AddKey(
+                    near_primitives::transaction::AddKeyAction {
+                        public_key: new_public_key.clone(),
+                        access_key: near_primitives::account::AccessKey {
+                            nonce: 0,
+                            permission,
+                        },
+                    },
+                ),
+                near_primitives::transaction::Action::DeleteKey(
+                    near_primitives::transaction::DeleteKeyAction {
+                        public_key: old_public_key.clone(),
+                    },
+                ),
+            ],
+        };
+        // Until the transaction is sent, the new key is surely not on chain, so its files are
+        // removed if the checks fail or the user declines the transaction.
+        let signed_transaction =
+            match crate::commands::construct_transaction_command::sign_transaction::sign_online(
+                &connection_config,
+                unsigned_transaction,
+                &signer_key.private_key,
+            )
+            .await
+            {
+                Ok(signed_transaction) => signed_transaction,
+                Err(err) => {
+                    remove_new_key_files(&new_key_file_paths);
+                    return Err(err);
+                }
+            };
+        // If the outcome is unknown, the new key may already replace the old one on chain.
+        let transaction_info = match crate::commands::construct_transaction_command::sign_transaction::send_transaction(
+            &connection_config,
+            signed_transaction,
+        )
+        .await
+        {
+            Ok(transaction_info) => transaction_info,
+            Err(err) => {
+                for new_key_file_path in &new_key_file_paths {
+                    println!(
+                        "The outcome of the transaction is unknown, the new key file {} is kept",
+                        new_key_file_path.display()
+                    );
+                }
+                return Err(err);
+            }
+        };
+        let is_successful = !matches!(
+            transaction_info.status,
+            near_primitives::views::FinalExecutionStatus::Failure(_)
+        );
+        crate::common::print_transaction_status(transaction_info, Some(connection_config));
+        if !is_successful {
+            remove_new_key_files(&new_key_file_paths);
+            return Ok(());
+        }
+
+        for file_path in old_key_file_paths {
+            std::fs::remove_file(&file_path).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to remove the old access key file {}: {}",
+                    file_path.display(),
+                    err
+                ))
+            })?;
+            println!("The old access key file {} is removed", file_path.display());
+        }
+        // The legacy `<account_id>.json` file is used for signing in offline mode, so if it had
+        // the old key or did not exist, it gets the new full access key.
+        if let Some(new_key_file_path) = new_key_file_paths.first() {
+            if is_full_access && !legacy_key_file_path.exists() {
+                std::fs::copy(new_key_file_path, &legacy_key_file_path)?;
+                println!(
+                    "The data for the access key is saved in a file {}",
+                    legacy_key_file_path.display()
+                );
+            }
+        }
+        println!(
+            "The access key {} of <{}> has been replaced with {}",
+            old_public_key, account_id, new_public_key
+        );
+        Ok(())
+    }
+}
+
+/// Finds the keychain files with the access key of the account: the legacy `<account_id>.json`
+/// file and the files in the `<account_id>` directory.
+fn find_key_files(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let mut keychain_path = dirs::home_dir().expect("Impossible to get your home dir!");
+    keychain_path.push(connection_config.dir_name());
+    let mut file_paths = vec![keychain_path.join(format!("{}.json", account_id))];
+    let account_dir_path = keychain_path.join(account_id.to_string());
+    if account_dir_path.is_dir() {
+        for entry in account_dir_path.read_dir()? {
+            file_paths.push(entry?.path());
+        }
+    }
+    Ok(file_paths
+        .into_iter()
+        .filter(|file_path| {
+            file_path.is_file()
+                && crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::read_access_key_file(file_path)
+                    .map_or(false, |user| &user.public_key == public_key)
+        })
+        .collect())
+}
+
+fn remove_new_key_files(new_key_file_paths: &[std::path::PathBuf]) {
+    for new_key_file_path in new_key_file_paths {
+        if std::fs::remove_file(new_key_file_path).is_ok() {
+            println!(
+                "The access key was not rotated, the new key file {} is removed",
+                new_key_file_path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::StakingCommandNetworkContext)]
+#[interactive_clap(output_context = crate::common::SignerContext)]
+pub struct Delegator {
+    #[interactive_clap(skip_default_from_cli)]
+    pub delegator_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub staking_action: super::StakingAction,
+}
+
+struct DelegatorContext {
+    connection_config: crate::common::ConnectionConfig,
+    delegator_account_id: crate::types::account_id::AccountId,
+}
+
+impl DelegatorContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::StakingCommandNetworkContext,
+        scope: &<Delegator as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            delegator_account_id: scope.delegator_account_id.clone(),
+        }
+    }
+}
+
+impl From<DelegatorContext> for crate::common::SignerContext {
+    fn from(item: DelegatorContext) -> Self {
+        Self {
+            connection_config: Some(item.connection_config),
+            signer_account_id: item.delegator_account_id,
+        }
+    }
+}
+
+impl Delegator {
+    fn from_cli_delegator_account_id(
+        optional_cli_delegator_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::StakingCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_delegator_account_id {
+            Some(cli_delegator_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_delegator_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_delegator_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_delegator_account_id);
+                    Self::input_delegator_account_id(&context)
+                }
+            },
+            None => Self::input_delegator_account_id(&context),
+        }
+    }
+
+    fn input_delegator_account_id(
+        context: &super::operation_mode::online_mode::select_server::StakingCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the account ID of the delegator?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.delegator_account_id.clone().into(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.staking_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,214 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod delegator;
+pub mod operation_mode;
+mod pool_action;
+mod pool_amount_action;
+mod view_delegations;
+
+const STAKING_POOL_GAS: u64 = 125_000_000_000_000; // 125 TeraGas
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = crate::common::SignerContext)]
+///What do you want to do with the delegated tokens?
+pub enum StakingAction {
+    #[strum_discriminants(strum(message = "View the delegations to staking pools"))]
+    /// View staked, unstaked and available for withdrawal balances in the staking pools
+    ViewDelegations(self::view_delegations::ViewDelegations),
+    #[strum_discriminants(strum(message = "Deposit and stake tokens to a staking pool"))]
+    /// Deposit and stake tokens to a staking pool (deposit_and_stake)
+    DepositAndStake(self::pool_amount_action::PoolAmountAction),
+    #[strum_discriminants(strum(message = "Unstake a part of the staked tokens"))]
+    /// Unstake a part of the staked tokens (unstake)
+    Unstake(self::pool_amount_action::PoolAmountAction),
+    #[strum_discriminants(strum(message = "Unstake all the staked tokens"))]
+    /// Unstake all the staked tokens (unstake_all)
+    UnstakeAll(self::pool_action::PoolAction),
+    #[strum_discriminants(strum(message = "Withdraw a part of the unstaked tokens"))]
+    /// Withdraw a part of the unstaked tokens (withdraw)
+    Withdraw(self::pool_amount_action::PoolAmountAction),
+    #[strum_discriminants(strum(message = "Withdraw all the unstaked tokens"))]
+    /// Withdraw all the unstaked tokens (withdraw_all)
+    WithdrawAll(self::pool_action::PoolAction),
+}
+
+impl StakingAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let delegator_account_id = prepopulated_unsigned_transaction.signer_id.clone();
+        match self {
+            Self::ViewDelegations(view_delegations) => {
+                view_delegations
+                    .process(network_connection_config, delegator_account_id)
+                    .await
+            }
+            Self::DepositAndStake(pool_amount_action) => {
+                pool_amount_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "deposit_and_stake",
+                    )
+                    .await
+            }
+            Self::Unstake(pool_amount_action) => {
+                let delegation = get_delegation(
+                    &network_connection_config,
+                    pool_amount_action.pool_account_id.clone().into(),
+                    delegator_account_id.clone(),
+                )
+                .await?;
+                if pool_amount_action.amount.to_yoctonear() > delegation.staked_balance {
+                    return Err(color_eyre::Report::msg(format!(
+                        "<{}> has only {} staked in <{}>.",
+                        delegator_account_id,
+                        crate::common::NearBalance::from_yoctonear(delegation.staked_balance),
+                        pool_amount_action.pool_account_id
+                    )));
+                }
+                pool_amount_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "unstake",
+                    )
+                    .await
+            }
+            Self::UnstakeAll(pool_action) => {
+                pool_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "unstake_all",
+                    )
+                    .await
+            }
+            Self::Withdraw(pool_amount_action) => {
+                let delegation = get_delegation(
+                    &network_connection_config,
+                    pool_amount_action.pool_account_id.clone().into(),
+                    delegator_account_id.clone(),
+                )
+                .await?;
+                if pool_amount_action.amount.to_yoctonear() > delegation.unstaked_balance {
+                    return Err(color_eyre::Report::msg(format!(
+                        "<{}> has only {} unstaked in <{}>.",
+                        delegator_account_id,
+                        crate::common::NearBalance::from_yoctonear(delegation.unstaked_balance),
+                        pool_amount_action.pool_account_id
+                    )));
+                }
+                if !delegation.can_withdraw {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The unstaked balance of <{}> in <{}> is not available for withdrawal yet (it takes 4 epochs after unstaking).",
+                        delegator_account_id, pool_amount_action.pool_account_id
+                    )));
+                }
+                pool_amount_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "withdraw",
+                    )
+                    .await
+            }
+            Self::WithdrawAll(pool_action) => {
+                let delegation = get_delegation(
+                    &network_connection_config,
+                    pool_action.pool_account_id.clone().into(),
+                    delegator_account_id.clone(),
+                )
+                .await?;
+                if !delegation.can_withdraw {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The unstaked balance of <{}> in <{}> is not available for withdrawal yet (it takes 4 epochs after unstaking).",
+                        delegator_account_id, pool_action.pool_account_id
+                    )));
+                }
+                pool_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "withdraw_all",
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// The delegator account in the staking pool (the `get_account` view method of the staking pool contract).
+#[derive(Debug, Clone, Default)]
+struct Delegation {
+    staked_balance: u128,
+    unstaked_balance: u128,
+    can_withdraw: bool,
+}
+
+async fn get_delegation(
+    network_connection_config: &crate::common::ConnectionConfig,
+    pool_account_id: near_primitives::types::AccountId,
+    delegator_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Delegation> {
+    let pool_account = crate::common::call_view_function(
+        network_connection_config,
+        pool_account_id.clone(),
+        "get_account",
+        serde_json::json!({ "account_id": delegator_account_id }),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    let parse_balance = |field: &str| {
+        pool_account[field]
+            .as_str()
+            .and_then(|balance| balance.parse::<u128>().ok())
+            .ok_or_else(|| {
+                color_eyre::Report::msg(format!(
+                    "The staking pool <{}> returned an invalid {}: {}",
+                    pool_account_id, field, pool_account[field]
+                ))
+            })
+    };
+    Ok(Delegation {
+        staked_balance: parse_balance("staked_balance")?,
+        unstaked_balance: parse_balance("unstaked_balance")?,
+        can_withdraw: pool_account["can_withdraw"].as_bool().unwrap_or(false),
+    })
+}
+
+fn input_pool_account_id(
+    context: &crate::common::SignerContext,
+) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+    println!();
+    loop {
+        let account_id: crate::types::account_id::AccountId = Input::new()
+            .with_prompt("What is the account ID of the staking pool?")
+            .interact_text()?;
+        match &context.connection_config {
+            Some(connection_config) => {
+                match crate::common::get_account_state(
+                    connection_config,
+                    account_id.clone().into(),
+                )? {
+                    Some(account_view)
+                        if account_view.code_hash
+                            != near_primitives::hash::CryptoHash::default() =>
+                    {
+                        break Ok(account_id)
+                    }
+                    Some(_) => println!(
+                        "Contract code is not deployed to this account <{}>.",
+                        account_id
+                    ),
+                    None => println!("Account <{}> doesn't exist", account_id),
+                }
+            }
+            None => break Ok(account_id),
+        }
+    }
+}
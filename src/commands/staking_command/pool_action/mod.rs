@@ -0,0 +1,55 @@
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct PoolAction {
+    pub pool_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl PoolAction {
+    fn input_pool_account_id(
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        super::input_pool_account_id(context)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method_name: &str,
+    ) -> crate::CliResult {
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_string(),
+                args: b"{}".to_vec(),
+                gas: super::STAKING_POOL_GAS,
+                deposit: 0,
+            },
+        ));
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.pool_account_id.clone().into(),
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                );
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct PoolAmountAction {
+    pub pool_account_id: crate::types::account_id::AccountId,
+    pub amount: crate::common::NearBalance,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl PoolAmountAction {
+    fn input_pool_account_id(
+        context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        super::input_pool_account_id(context)
+    }
+
+    fn input_amount(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("How many NEAR Tokens? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method_name: &str,
+    ) -> crate::CliResult {
+        let (args, deposit) = match method_name {
+            "deposit_and_stake" => (serde_json::json!({}), self.amount.to_yoctonear()),
+            _ => (
+                serde_json::json!({ "amount": self.amount.to_yoctonear().to_string() }),
+                0,
+            ),
+        };
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_string(),
+                args: args.to_string().into_bytes(),
+                gas: super::STAKING_POOL_GAS,
+                deposit,
+            },
+        ));
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.pool_account_id.clone().into(),
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                );
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
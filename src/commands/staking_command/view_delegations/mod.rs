@@ -0,0 +1,75 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct ViewDelegations {
+    pool_account_ids: crate::types::vec_string::VecString,
+}
+
+impl ViewDelegations {
+    fn input_pool_account_ids(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::vec_string::VecString> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter a comma-separated list of staking pools (example: zavodil.poolv1.near, figment.poolv1.near)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        delegator_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let mut total = super::Delegation::default();
+        println!("\nDelegations of <{}>:", delegator_account_id);
+        for pool_account_id in self
+            .pool_account_ids
+            .0
+            .iter()
+            .filter(|pool| !pool.is_empty())
+        {
+            let pool_account_id: near_primitives::types::AccountId =
+                pool_account_id.parse().map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Invalid staking pool account ID <{}>: {}",
+                        pool_account_id, err
+                    ))
+                })?;
+            let delegation = match super::get_delegation(
+                &network_connection_config,
+                pool_account_id.clone(),
+                delegator_account_id.clone(),
+            )
+            .await
+            {
+                Ok(delegation) => delegation,
+                Err(err) => {
+                    println!("\n<{}>: {}", pool_account_id, err);
+                    continue;
+                }
+            };
+            println!(
+                "\n<{}>\n  Staked: {}\n  Unstaked: {}{}",
+                pool_account_id,
+                crate::common::NearBalance::from_yoctonear(delegation.staked_balance),
+                crate::common::NearBalance::from_yoctonear(delegation.unstaked_balance),
+                if delegation.unstaked_balance == 0 {
+                    ""
+                } else if delegation.can_withdraw {
+                    " (can withdraw)"
+                } else {
+                    " (not available for withdrawal yet)"
+                }
+            );
+            total.staked_balance += delegation.staked_balance;
+            total.unstaked_balance += delegation.unstaked_balance;
+        }
+        println!(
+            "\nTotal staked: {}\nTotal unstaked: {}",
+            crate::common::NearBalance::from_yoctonear(total.staked_balance),
+            crate::common::NearBalance::from_yoctonear(total.unstaked_balance)
+        );
+        Ok(())
+    }
+}
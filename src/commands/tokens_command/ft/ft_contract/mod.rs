@@ -0,0 +1,99 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::FtCommandNetworkContext)]
+#[interactive_clap(output_context = FtContractContext)]
+pub struct FtContract {
+    #[interactive_clap(skip_default_from_cli)]
+    pub ft_contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub ft_action: super::FtAction,
+}
+
+#[derive(Clone)]
+pub struct FtContractContext {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub ft_contract_account_id: crate::types::account_id::AccountId,
+}
+
+impl FtContractContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::FtCommandNetworkContext,
+        scope: &<FtContract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            ft_contract_account_id: scope.ft_contract_account_id.clone(),
+        }
+    }
+}
+
+impl FtContract {
+    fn from_cli_ft_contract_account_id(
+        optional_cli_ft_contract_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::FtCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_ft_contract_account_id {
+            Some(cli_ft_contract_account_id) => {
+                if Self::is_ft_contract(context, &cli_ft_contract_account_id) {
+                    Ok(cli_ft_contract_account_id)
+                } else {
+                    Self::input_ft_contract_account_id(&context)
+                }
+            }
+            None => Self::input_ft_contract_account_id(&context),
+        }
+    }
+
+    fn input_ft_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::FtCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let ft_contract_account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the account ID of the fungible token contract?")
+                .interact_text()?;
+            if Self::is_ft_contract(context, &ft_contract_account_id) {
+                break Ok(ft_contract_account_id);
+            }
+        }
+    }
+
+    fn is_ft_contract(
+        context: &super::operation_mode::online_mode::select_server::FtCommandNetworkContext,
+        ft_contract_account_id: &crate::types::account_id::AccountId,
+    ) -> bool {
+        match actix::System::new().block_on(crate::common::get_ft_metadata(
+            &context.connection_config,
+            ft_contract_account_id.clone().into(),
+        )) {
+            Ok(metadata) => {
+                println!(
+                    "Fungible token: {} ({}), decimals: {}",
+                    metadata.name, metadata.symbol, metadata.decimals
+                );
+                true
+            }
+            Err(err) => {
+                println!(
+                    "<{}> is not a fungible token contract: {}",
+                    ft_contract_account_id, err
+                );
+                false
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.ft_action
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                self.ft_contract_account_id.into(),
+            )
+            .await
+    }
+}
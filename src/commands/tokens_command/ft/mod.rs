@@ -0,0 +1,161 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod ft_contract;
+pub mod operation_mode;
+mod sender;
+mod transfer;
+mod transfer_call;
+mod view_balance;
+
+const FT_TRANSFER_GAS: u64 = 30_000_000_000_000; // 30 TeraGas
+const STORAGE_DEPOSIT_GAS: u64 = 30_000_000_000_000; // 30 TeraGas
+const ONE_YOCTONEAR: u128 = 1;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = self::ft_contract::FtContractContext)]
+///What do you want to do with the fungible token?
+pub enum FtAction {
+    #[strum_discriminants(strum(message = "View the token balance of an account"))]
+    /// View the token balance of an account (ft_balance_of)
+    ViewBalance(self::view_balance::ViewBalance),
+    #[strum_discriminants(strum(message = "View the token metadata"))]
+    /// View the token metadata (ft_metadata)
+    ViewMetadata,
+    #[strum_discriminants(strum(message = "Transfer tokens to an account"))]
+    /// Transfer tokens to an account (ft_transfer)
+    Transfer(self::transfer::FtTransfer),
+    #[strum_discriminants(strum(message = "Transfer tokens to a contract and call it"))]
+    /// Transfer tokens to a contract and call its ft_on_transfer method (ft_transfer_call)
+    TransferCall(self::transfer_call::FtTransferCall),
+}
+
+impl FtAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        ft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        match self {
+            Self::ViewBalance(view_balance) => {
+                view_balance
+                    .process(network_connection_config, ft_contract_account_id)
+                    .await
+            }
+            Self::ViewMetadata => {
+                let metadata = crate::common::get_ft_metadata(
+                    &network_connection_config,
+                    ft_contract_account_id.clone(),
+                )
+                .await?;
+                println!(
+                    "\nFungible token <{}>\n\
+                    Name: {}\n\
+                    Symbol: {}\n\
+                    Decimals: {}\n\
+                    Spec: {}",
+                    ft_contract_account_id,
+                    metadata.name,
+                    metadata.symbol,
+                    metadata.decimals,
+                    metadata.spec
+                );
+                Ok(())
+            }
+            Self::Transfer(transfer) => {
+                transfer
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        ft_contract_account_id,
+                    )
+                    .await
+            }
+            Self::TransferCall(transfer_call) => {
+                transfer_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        ft_contract_account_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// Converts the amount to the token base units and checks that the sender has enough tokens.
+async fn get_transfer_amount(
+    network_connection_config: &crate::common::ConnectionConfig,
+    ft_contract_account_id: near_primitives::types::AccountId,
+    sender_account_id: near_primitives::types::AccountId,
+    amount: &crate::types::ft_amount::FtAmount,
+) -> color_eyre::eyre::Result<u128> {
+    let metadata =
+        crate::common::get_ft_metadata(network_connection_config, ft_contract_account_id.clone())
+            .await?;
+    if let Some(symbol) = &amount.symbol {
+        if !symbol.eq_ignore_ascii_case(&metadata.symbol) {
+            return Err(color_eyre::Report::msg(format!(
+                "The token <{}> symbol is {}, but the amount is given in {}.",
+                ft_contract_account_id, metadata.symbol, symbol
+            )));
+        }
+    }
+    let amount = amount
+        .to_base_units(metadata.decimals)
+        .map_err(color_eyre::Report::msg)?;
+    let sender_balance = crate::common::get_ft_balance(
+        network_connection_config,
+        ft_contract_account_id,
+        sender_account_id.clone(),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    if amount > sender_balance {
+        return Err(color_eyre::Report::msg(format!(
+            "<{}> has only {}, which is not enough to transfer {}.",
+            sender_account_id,
+            metadata.balance(sender_balance),
+            metadata.balance(amount)
+        )));
+    }
+    println!("\nTransferring {}", metadata.balance(amount));
+    Ok(amount)
+}
+
+/// Returns the `storage_deposit` action if the receiver is not registered with the token contract yet.
+async fn get_storage_deposit_action(
+    network_connection_config: &crate::common::ConnectionConfig,
+    ft_contract_account_id: near_primitives::types::AccountId,
+    receiver_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<near_primitives::transaction::Action>> {
+    let storage_deposit = match crate::common::get_required_storage_deposit(
+        network_connection_config,
+        ft_contract_account_id,
+        receiver_account_id.clone(),
+    )
+    .await?
+    {
+        Some(storage_deposit) => storage_deposit,
+        None => return Ok(None),
+    };
+    println!(
+        "<{}> is not registered with the token contract, so {} of storage deposit will be paid for it.",
+        receiver_account_id, storage_deposit
+    );
+    Ok(Some(near_primitives::transaction::Action::FunctionCall(
+        near_primitives::transaction::FunctionCallAction {
+            method_name: "storage_deposit".to_string(),
+            args: serde_json::json!({
+                "account_id": receiver_account_id,
+                "registration_only": true,
+            })
+            .to_string()
+            .into_bytes(),
+            gas: STORAGE_DEPOSIT_GAS,
+            deposit: storage_deposit.to_yoctonear(),
+        },
+    )))
+}
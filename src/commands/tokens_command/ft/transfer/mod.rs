@@ -0,0 +1,87 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::ft_contract::FtContractContext)]
+pub struct FtTransfer {
+    receiver_account_id: crate::types::account_id::AccountId,
+    amount: crate::types::ft_amount::FtAmount,
+    #[interactive_clap(named_arg)]
+    /// Specify a sender
+    sender: super::sender::Sender,
+}
+
+impl FtTransfer {
+    fn input_receiver_account_id(
+        context: &super::ft_contract::FtContractContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        println!();
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the account ID of the receiver?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+
+    fn input_amount(
+        _context: &super::ft_contract::FtContractContext,
+    ) -> color_eyre::eyre::Result<crate::types::ft_amount::FtAmount> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("How many tokens do you want to transfer? (example: 10.5 or 10.5 USDC)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        ft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let amount = super::get_transfer_amount(
+            &network_connection_config,
+            ft_contract_account_id.clone(),
+            self.sender.sender_account_id.clone().into(),
+            &self.amount,
+        )
+        .await?;
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        if let Some(storage_deposit_action) = super::get_storage_deposit_action(
+            &network_connection_config,
+            ft_contract_account_id.clone(),
+            self.receiver_account_id.clone().into(),
+        )
+        .await?
+        {
+            actions.push(storage_deposit_action);
+        }
+        actions.push(near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: "ft_transfer".to_string(),
+                args: serde_json::json!({
+                    "receiver_id": self.receiver_account_id.to_string(),
+                    "amount": amount.to_string(),
+                })
+                .to_string()
+                .into_bytes(),
+                gas: super::FT_TRANSFER_GAS,
+                deposit: super::ONE_YOCTONEAR,
+            },
+        ));
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: ft_contract_account_id,
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        self.sender
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,45 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::ft_contract::FtContractContext)]
+pub struct ViewBalance {
+    owner_account_id: crate::types::account_id::AccountId,
+}
+
+impl ViewBalance {
+    fn input_owner_account_id(
+        _context: &super::ft_contract::FtContractContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("What account ID do you need to view the balance of?")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        ft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let metadata = crate::common::get_ft_metadata(
+            &network_connection_config,
+            ft_contract_account_id.clone(),
+        )
+        .await?;
+        let balance = crate::common::get_ft_balance(
+            &network_connection_config,
+            ft_contract_account_id.clone(),
+            self.owner_account_id.clone().into(),
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        println!(
+            "\n<{}> has {} ({} base units) of <{}>",
+            self.owner_account_id,
+            metadata.balance(balance),
+            balance,
+            ft_contract_account_id
+        );
+        Ok(())
+    }
+}
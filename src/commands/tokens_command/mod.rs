@@ -0,0 +1,55 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod ft;
+mod nft;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct TokensCommand {
+    #[interactive_clap(subcommand)]
+    pub standard: TokenStandard,
+}
+
+impl TokensCommand {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.standard
+            .process(prepopulated_unsigned_transaction)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///Choose a token standard
+pub enum TokenStandard {
+    #[strum_discriminants(strum(message = "Fungible tokens (NEP-141)"))]
+    /// View and transfer fungible tokens (NEP-141)
+    Ft(self::ft::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Non-fungible tokens (NEP-171)"))]
+    /// View and transfer non-fungible tokens (NEP-171)
+    Nft(self::nft::operation_mode::OperationMode),
+}
+
+impl TokenStandard {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        match self {
+            Self::Ft(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+            Self::Nft(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+        }
+    }
+}
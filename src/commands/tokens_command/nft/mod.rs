@@ -0,0 +1,120 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod nft_contract;
+pub mod operation_mode;
+mod sender;
+mod transfer;
+mod view_token;
+mod view_tokens_for_owner;
+
+const NFT_TRANSFER_GAS: u64 = 30_000_000_000_000; // 30 TeraGas
+const ONE_YOCTONEAR: u128 = 1;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = self::nft_contract::NftContractContext)]
+///What do you want to do with the non-fungible tokens?
+pub enum NftAction {
+    #[strum_discriminants(strum(message = "View the tokens owned by an account"))]
+    /// View the tokens owned by an account (nft_tokens_for_owner)
+    ViewTokensForOwner(self::view_tokens_for_owner::ViewTokensForOwner),
+    #[strum_discriminants(strum(message = "View a token and its metadata"))]
+    /// View a token and its metadata (nft_token)
+    ViewToken(self::view_token::ViewToken),
+    #[strum_discriminants(strum(message = "Transfer a token to an account"))]
+    /// Transfer a token to an account (nft_transfer)
+    Transfer(self::transfer::NftTransfer),
+}
+
+impl NftAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        nft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        match self {
+            Self::ViewTokensForOwner(view_tokens_for_owner) => {
+                view_tokens_for_owner
+                    .process(network_connection_config, nft_contract_account_id)
+                    .await
+            }
+            Self::ViewToken(view_token) => {
+                view_token
+                    .process(network_connection_config, nft_contract_account_id)
+                    .await
+            }
+            Self::Transfer(transfer) => {
+                transfer
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        nft_contract_account_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// Non-fungible token contract metadata (NEP-177).
+#[derive(Debug, Clone, serde::Deserialize)]
+struct NftContractMetadata {
+    spec: String,
+    name: String,
+    symbol: String,
+}
+
+async fn get_nft_contract_metadata(
+    network_connection_config: &crate::common::ConnectionConfig,
+    nft_contract_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<NftContractMetadata> {
+    let metadata = crate::common::call_view_function(
+        network_connection_config,
+        nft_contract_account_id.clone(),
+        "nft_metadata",
+        serde_json::json!({}),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    serde_json::from_value(metadata).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The contract <{}> returned invalid non-fungible token metadata: {:?}",
+            nft_contract_account_id, err
+        ))
+    })
+}
+
+/// Returns the token (NEP-171 `nft_token`) or `None` if it doesn't exist.
+async fn get_nft_token(
+    network_connection_config: &crate::common::ConnectionConfig,
+    nft_contract_account_id: near_primitives::types::AccountId,
+    token_id: &str,
+) -> color_eyre::eyre::Result<Option<serde_json::Value>> {
+    let token = crate::common::call_view_function(
+        network_connection_config,
+        nft_contract_account_id,
+        "nft_token",
+        serde_json::json!({ "token_id": token_id }),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    Ok(if token.is_null() { None } else { Some(token) })
+}
+
+/// Prints the token ID, the owner and the non-empty fields of the token metadata (NEP-177).
+fn print_nft_token(token: &serde_json::Value) {
+    println!(
+        "Token ID: {}\n  Owner: {}",
+        token["token_id"].as_str().unwrap_or_default(),
+        token["owner_id"].as_str().unwrap_or_default()
+    );
+    if let Some(metadata) = token["metadata"].as_object() {
+        for (field, value) in metadata.iter().filter(|(_, value)| !value.is_null()) {
+            match value.as_str() {
+                Some(value) => println!("  {}: {}", field, value),
+                None => println!("  {}: {}", field, value),
+            }
+        }
+    }
+}
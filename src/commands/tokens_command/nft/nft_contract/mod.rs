@@ -0,0 +1,99 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::NftCommandNetworkContext)]
+#[interactive_clap(output_context = NftContractContext)]
+pub struct NftContract {
+    #[interactive_clap(skip_default_from_cli)]
+    pub nft_contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub nft_action: super::NftAction,
+}
+
+#[derive(Clone)]
+pub struct NftContractContext {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub nft_contract_account_id: crate::types::account_id::AccountId,
+}
+
+impl NftContractContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::NftCommandNetworkContext,
+        scope: &<NftContract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            nft_contract_account_id: scope.nft_contract_account_id.clone(),
+        }
+    }
+}
+
+impl NftContract {
+    fn from_cli_nft_contract_account_id(
+        optional_cli_nft_contract_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::NftCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_nft_contract_account_id {
+            Some(cli_nft_contract_account_id) => {
+                if Self::is_nft_contract(context, &cli_nft_contract_account_id) {
+                    Ok(cli_nft_contract_account_id)
+                } else {
+                    Self::input_nft_contract_account_id(&context)
+                }
+            }
+            None => Self::input_nft_contract_account_id(&context),
+        }
+    }
+
+    fn input_nft_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::NftCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let nft_contract_account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the account ID of the non-fungible token contract?")
+                .interact_text()?;
+            if Self::is_nft_contract(context, &nft_contract_account_id) {
+                break Ok(nft_contract_account_id);
+            }
+        }
+    }
+
+    fn is_nft_contract(
+        context: &super::operation_mode::online_mode::select_server::NftCommandNetworkContext,
+        nft_contract_account_id: &crate::types::account_id::AccountId,
+    ) -> bool {
+        match actix::System::new().block_on(super::get_nft_contract_metadata(
+            &context.connection_config,
+            nft_contract_account_id.clone().into(),
+        )) {
+            Ok(metadata) => {
+                println!(
+                    "Non-fungible token collection: {} ({}), {}",
+                    metadata.name, metadata.symbol, metadata.spec
+                );
+                true
+            }
+            Err(err) => {
+                println!(
+                    "<{}> is not a non-fungible token contract: {}",
+                    nft_contract_account_id, err
+                );
+                false
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.nft_action
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                self.nft_contract_account_id.into(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,90 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::nft_contract::NftContractContext)]
+pub struct NftTransfer {
+    receiver_account_id: crate::types::account_id::AccountId,
+    token_id: String,
+    #[interactive_clap(named_arg)]
+    /// Specify a sender
+    sender: super::sender::Sender,
+}
+
+impl NftTransfer {
+    fn input_receiver_account_id(
+        context: &super::nft_contract::NftContractContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        println!();
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the account ID of the receiver?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+
+    fn input_token_id(
+        _context: &super::nft_contract::NftContractContext,
+    ) -> color_eyre::eyre::Result<String> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("What is the ID of the token to transfer?")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        nft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let token = super::get_nft_token(
+            &network_connection_config,
+            nft_contract_account_id.clone(),
+            &self.token_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            color_eyre::Report::msg(format!(
+                "Token <{}> doesn't exist on <{}>",
+                self.token_id, nft_contract_account_id
+            ))
+        })?;
+        let owner_id = token["owner_id"].as_str().unwrap_or_default();
+        if owner_id != self.sender.sender_account_id.to_string() {
+            println!(
+                "WARNING! Token <{}> is owned by <{}>, so the transfer on behalf of <{}> will succeed only if it is approved for it.",
+                self.token_id, owner_id, self.sender.sender_account_id
+            );
+        }
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: "nft_transfer".to_string(),
+                args: serde_json::json!({
+                    "receiver_id": self.receiver_account_id.to_string(),
+                    "token_id": self.token_id,
+                })
+                .to_string()
+                .into_bytes(),
+                gas: super::NFT_TRANSFER_GAS,
+                deposit: super::ONE_YOCTONEAR,
+            },
+        ));
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: nft_contract_account_id,
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        self.sender
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
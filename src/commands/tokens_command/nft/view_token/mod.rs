@@ -0,0 +1,42 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::nft_contract::NftContractContext)]
+pub struct ViewToken {
+    token_id: String,
+}
+
+impl ViewToken {
+    fn input_token_id(
+        _context: &super::nft_contract::NftContractContext,
+    ) -> color_eyre::eyre::Result<String> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("What is the token ID?")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        nft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        match super::get_nft_token(
+            &network_connection_config,
+            nft_contract_account_id.clone(),
+            &self.token_id,
+        )
+        .await?
+        {
+            Some(token) => {
+                println!();
+                super::print_nft_token(&token);
+                Ok(())
+            }
+            None => Err(color_eyre::Report::msg(format!(
+                "Token <{}> doesn't exist on <{}>",
+                self.token_id, nft_contract_account_id
+            ))),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+const DEFAULT_LIMIT: u64 = 10;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::nft_contract::NftContractContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct ViewTokensForOwner {
+    owner_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    from_index: Option<u64>,
+    #[interactive_clap(long)]
+    limit: Option<u64>,
+    #[interactive_clap(skip)]
+    is_interactive: bool,
+}
+
+impl ViewTokensForOwner {
+    pub fn from_cli(
+        optional_clap_variant: Option<<ViewTokensForOwner as interactive_clap::ToCli>::CliVariant>,
+        context: super::nft_contract::NftContractContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let cli_owner_account_id = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.owner_account_id);
+        // In the interactive mode the next pages are offered one by one
+        let is_interactive = cli_owner_account_id.is_none();
+        let owner_account_id = match cli_owner_account_id {
+            Some(cli_owner_account_id) => cli_owner_account_id,
+            None => Self::input_owner_account_id(&context)?,
+        };
+        let from_index = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.from_index);
+        let limit = optional_clap_variant.and_then(|clap_variant| clap_variant.limit);
+        Ok(Self {
+            owner_account_id,
+            from_index,
+            limit,
+            is_interactive,
+        })
+    }
+
+    fn input_owner_account_id(
+        _context: &super::nft_contract::NftContractContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("What account ID do you need to view the tokens of?")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        nft_contract_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT);
+        // nft_supply_for_owner is a part of the optional enumeration extension (NEP-181)
+        let supply = crate::common::call_view_function(
+            &network_connection_config,
+            nft_contract_account_id.clone(),
+            "nft_supply_for_owner",
+            serde_json::json!({ "account_id": self.owner_account_id.to_string() }),
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await
+        .ok()
+        .and_then(|supply| {
+            supply
+                .as_str()
+                .and_then(|supply| supply.parse::<u64>().ok())
+        });
+        println!(
+            "\nTokens of <{}> on <{}>{}",
+            self.owner_account_id,
+            nft_contract_account_id,
+            match supply {
+                Some(supply) => format!(" (total: {})", supply),
+                None => "".to_string(),
+            }
+        );
+        let mut from_index = self.from_index.unwrap_or(0);
+        loop {
+            let tokens = crate::common::call_view_function(
+                &network_connection_config,
+                nft_contract_account_id.clone(),
+                "nft_tokens_for_owner",
+                serde_json::json!({
+                    "account_id": self.owner_account_id.to_string(),
+                    "from_index": from_index.to_string(),
+                    "limit": limit,
+                }),
+                near_primitives::types::Finality::Final.into(),
+            )
+            .await?;
+            let tokens = tokens.as_array().cloned().unwrap_or_default();
+            if tokens.is_empty() {
+                println!("\nNo tokens found starting from index {}", from_index);
+                return Ok(());
+            }
+            for (index, token) in tokens.iter().enumerate() {
+                print!("\n#{} ", from_index + index as u64);
+                super::print_nft_token(token);
+            }
+            from_index += tokens.len() as u64;
+            let has_more = match supply {
+                Some(supply) => from_index < supply,
+                None => tokens.len() as u64 == limit,
+            };
+            if !has_more {
+                return Ok(());
+            }
+            if !self.is_interactive {
+                println!(
+                    "\nThere may be more tokens (use --from-index {})",
+                    from_index
+                );
+                return Ok(());
+            }
+            println!();
+            let choices = ["Yes, show the next page", "No, that's enough"];
+            if Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("Do you want to view more tokens?")
+                .items(&choices)
+                .default(0)
+                .interact()?
+                != 0
+            {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,938 @@
+use dialoguer::Input;
+use near_primitives::borsh::BorshSerialize;
+use std::io::Write;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::NearBalance;
+
+/// The number of times a transfer is signed again after it was rejected because of its nonce.
+const MAX_SIGN_ATTEMPTS: u32 = 3;
+
+const REPORT_HEADER: &str =
+    "line_number,receiver_id,amount,status,transaction_hash,error,signed_transaction";
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct Batch {
+    file_path: crate::types::path_buf::PathBuf,
+    report_file_path: crate::types::path_buf::PathBuf,
+    max_concurrency: u64,
+    #[interactive_clap(subcommand)]
+    submit: BatchSubmit,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = crate::common::SignerContext)]
+///How would you like to proceed?
+pub enum BatchSubmit {
+    #[strum_discriminants(strum(message = "I want to send the transfers to the network"))]
+    /// Send the transfers that are not completed in the report yet
+    Send,
+    #[strum_discriminants(strum(
+        message = "I only want to check the batch file and the balance of the sender"
+    ))]
+    /// Check the batch file and the balance of the sender without sending anything
+    Check,
+}
+
+/// A row of the batch file.
+#[derive(Debug, Clone, PartialEq)]
+struct BatchTransfer {
+    line_number: usize,
+    receiver_id: near_primitives::types::AccountId,
+    amount: NearBalance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransferStatus {
+    Pending,
+    Success,
+    Failure,
+    /// The transfer was rejected before execution too many times, it is sent again on resume.
+    NotSent,
+}
+
+impl TransferStatus {
+    fn is_completed(&self) -> bool {
+        matches!(self, Self::Success | Self::Failure)
+    }
+}
+
+impl std::fmt::Display for TransferStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Success => write!(f, "success"),
+            Self::Failure => write!(f, "failure"),
+            Self::NotSent => write!(f, "not_sent"),
+        }
+    }
+}
+
+impl std::str::FromStr for TransferStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "success" => Ok(Self::Success),
+            "failure" => Ok(Self::Failure),
+            "not_sent" => Ok(Self::NotSent),
+            _ => Err(format!("unknown transfer status <{}>", s)),
+        }
+    }
+}
+
+/// The last known state of a transfer in the report.
+#[derive(Debug, Clone)]
+struct ReportEntry {
+    status: TransferStatus,
+    /// The transaction of a pending transfer, it may have been sent already.
+    signed_transaction: Option<near_primitives::transaction::SignedTransaction>,
+}
+
+enum SendError {
+    /// The transaction was rejected before execution because of its nonce or block hash,
+    /// so it can be signed again.
+    Resign(String),
+    Failed(String),
+}
+
+impl Batch {
+    pub fn input_file_path(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        Ok(Input::new()
+            .with_prompt(
+                "Where is the batch file (CSV with the receiver and the amount on each line)?",
+            )
+            .interact_text()?)
+    }
+
+    pub fn input_report_file_path(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        Ok(Input::new()
+            .with_prompt(
+                "Where to save the report (an existing report is used to resume the batch)?",
+            )
+            .with_initial_text("batch-transfer-report.csv")
+            .interact_text()?)
+    }
+
+    pub fn input_max_concurrency(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<u64> {
+        Ok(Input::new()
+            .with_prompt("How many transfers can be sent at the same time?")
+            .with_initial_text("10")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let connection_config = network_connection_config.ok_or_else(|| {
+            color_eyre::Report::msg("Batch transfers can only be sent in online mode".to_string())
+        })?;
+        if self.max_concurrency == 0 {
+            return Err(color_eyre::Report::msg(
+                "The number of transfers sent at the same time must be greater than zero"
+                    .to_string(),
+            ));
+        }
+        let signer_id = prepopulated_unsigned_transaction.signer_id.clone();
+        // The batch is only signed with a full access key from the keychain, so it is looked up
+        // before the batch file and the fees are checked.
+        let access_key_file_path = crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::get_access_key_file_path(
+            &signer_id,
+            &connection_config,
+        )
+        .await?;
+        let signer = crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::read_access_key_file(
+            &access_key_file_path,
+        )
+        .map_err(|_| {
+            color_eyre::Report::msg(format!(
+                "Batch transfers are signed with a full access key from the keychain, but there is no full access key of <{}> in ~/{} (log in first, Ledger and private key signing are not supported for batches)",
+                signer_id,
+                connection_config.dir_name()
+            ))
+        })?;
+        let contents = std::fs::read_to_string(&self.file_path.0).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to read the batch file: {}", err))
+        })?;
+        let transfers = parse_batch_file(&contents).map_err(|errors| {
+            color_eyre::Report::msg(format!(
+                "The batch file <{}> is invalid:\n{}",
+                self.file_path,
+                errors.join("\n")
+            ))
+        })?;
+        let report = load_report(&self.report_file_path.0, &transfers)?;
+
+        let completed_count = report
+            .values()
+            .filter(|entry| entry.status.is_completed())
+            .count();
+        let remaining_transfers = transfers
+            .iter()
+            .filter(|transfer| {
+                report
+                    .get(&transfer.line_number)
+                    .map_or(true, |entry| !entry.status.is_completed())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut transaction_fees = std::collections::HashMap::new();
+        let mut total_amount: u128 = 0;
+        let mut total_fee: u128 = 0;
+        for transfer in &remaining_transfers {
+            let is_implicit = crate::common::is_64_len_hex(&transfer.receiver_id);
+            let transaction_fee = match transaction_fees.get(&is_implicit) {
+                Some(transaction_fee) => *transaction_fee,
+                None => {
+                    let transaction_fee = crate::common::get_transfer_transaction_fee(
+                        &connection_config,
+                        &signer_id,
+                        &transfer.receiver_id,
+                    )
+                    .await?
+                    .to_yoctonear();
+                    transaction_fees.insert(is_implicit, transaction_fee);
+                    transaction_fee
+                }
+            };
+            total_amount = total_amount
+                .checked_add(transfer.amount.to_yoctonear())
+                .ok_or_else(|| {
+                    color_eyre::Report::msg(
+                        "The total amount of the batch is too large".to_string(),
+                    )
+                })?;
+            total_fee = total_fee.checked_add(transaction_fee).ok_or_else(|| {
+                color_eyre::Report::msg(
+                    "The total transaction fee of the batch is too large".to_string(),
+                )
+            })?;
+        }
+        let (access_key_view, block_hash) =
+            get_access_key_and_block_hash(&connection_config, &signer_id, &signer.public_key)
+                .await?;
+        let transactions = remaining_transfers
+            .iter()
+            .map(|transfer| {
+                transfer_transaction(&signer_id, &signer.public_key, transfer, 0, block_hash)
+            })
+            .collect::<Vec<_>>();
+        // The same checks as for a single transaction, but the receivers are looked up once and
+        // the safety policy asks for a single confirmation of the whole batch.
+        let safety_policy = crate::commands::construct_transaction_command::sign_transaction::safety_policy::SafetyPolicy::load(
+            &connection_config,
+        )?;
+        safety_policy.check_batch_limits(&transactions)?;
+        let mut checked_receiver_ids = std::collections::HashSet::new();
+        for transaction in &transactions {
+            crate::commands::construct_transaction_command::sign_transaction::check_access_key_permission(
+                transaction,
+                &access_key_view.permission,
+            )?;
+            if checked_receiver_ids.insert(transaction.receiver_id.clone()) {
+                crate::commands::construct_transaction_command::sign_transaction::check_receiver_exists(
+                    &connection_config,
+                    transaction,
+                )
+                .await?;
+            }
+        }
+        let account_transfer_allowance = crate::common::get_account_transfer_allowance_with_fee(
+            &connection_config,
+            signer_id.clone(),
+            NearBalance::from_yoctonear(total_fee),
+        )
+        .await?;
+
+        println!(
+            "\nThe batch file has {} transfers: {} completed, {} to send.",
+            transfers.len(),
+            completed_count,
+            remaining_transfers.len()
+        );
+        println!(
+            "Total amount to send: {}\nTotal transaction fees: ~{}",
+            NearBalance::from_yoctonear(total_amount),
+            NearBalance::from_yoctonear(total_fee)
+        );
+        if total_amount
+            > account_transfer_allowance
+                .transfer_allowance()
+                .to_yoctonear()
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "The total amount of the batch is larger than the available balance:{}",
+                account_transfer_allowance
+            )));
+        }
+        println!("{}", account_transfer_allowance);
+
+        match self.submit {
+            BatchSubmit::Check => Ok(()),
+            BatchSubmit::Send => {
+                safety_policy.confirm_batch(&transactions)?;
+                send_transfers(
+                    &connection_config,
+                    &signer_id,
+                    &signer,
+                    remaining_transfers,
+                    &report,
+                    &self.report_file_path.0,
+                    self.max_concurrency as usize,
+                )
+                .await
+            }
+        }
+    }
+}
+
+/// Parses the batch file: one `receiver,amount` per line, the amount is in NEAR unless a unit
+/// is given (`1.5`, `1.5 NEAR` or `1500000 yoctoNEAR`). Empty lines, `#` comments and a header
+/// line are skipped. All the invalid lines are reported at once.
+fn parse_batch_file(contents: &str) -> Result<Vec<BatchTransfer>, Vec<String>> {
+    let mut transfers: Vec<BatchTransfer> = vec![];
+    let mut errors = vec![];
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+        let (receiver, amount) = match fields.as_slice() {
+            [receiver, amount] => (*receiver, *amount),
+            _ => {
+                errors.push(format!(
+                    "line {}: expected <receiver>,<amount>, found <{}>",
+                    line_number, line
+                ));
+                continue;
+            }
+        };
+        if transfers.is_empty()
+            && errors.is_empty()
+            && ["receiver", "receiver_id", "account_id"].contains(&receiver)
+        {
+            continue;
+        }
+        let receiver_id = match receiver.parse::<near_primitives::types::AccountId>() {
+            Ok(receiver_id) => receiver_id,
+            Err(err) => {
+                errors.push(format!(
+                    "line {}: <{}> is not a valid account ID ({})",
+                    line_number, receiver, err
+                ));
+                continue;
+            }
+        };
+        let amount = if amount.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            format!("{} NEAR", amount)
+        } else {
+            amount.to_string()
+        };
+        match amount.parse::<NearBalance>() {
+            Ok(amount) if amount.to_yoctonear() == 0 => {
+                errors.push(format!("line {}: the amount must not be zero", line_number))
+            }
+            Ok(amount) => {
+                if let Some(transfer) = transfers
+                    .iter()
+                    .find(|transfer| transfer.receiver_id == receiver_id)
+                {
+                    println!(
+                        "Warning: <{}> is a receiver on lines {} and {}",
+                        receiver_id, transfer.line_number, line_number
+                    );
+                }
+                transfers.push(BatchTransfer {
+                    line_number,
+                    receiver_id,
+                    amount,
+                })
+            }
+            Err(err) => errors.push(format!("line {}: {}", line_number, err)),
+        }
+    }
+    if transfers.is_empty() && errors.is_empty() {
+        errors.push("there are no transfers in the file".to_string());
+    }
+    if errors.is_empty() {
+        Ok(transfers)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Reads the report of the previous runs of the same batch.
+fn load_report(
+    report_file_path: &std::path::Path,
+    transfers: &[BatchTransfer],
+) -> color_eyre::eyre::Result<std::collections::HashMap<usize, ReportEntry>> {
+    if !report_file_path.exists() {
+        return Ok(std::collections::HashMap::new());
+    }
+    let contents = std::fs::read_to_string(report_file_path)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to read the report: {}", err)))?;
+    parse_report(&contents, transfers).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The report <{}> can not be used to resume the batch: {}",
+            report_file_path.display(),
+            err
+        ))
+    })
+}
+
+/// Parses the report rows; the last row of a line wins. A pending transfer must keep its signed
+/// transaction: it may have been executed, so it is never signed again with a new nonce.
+fn parse_report(
+    contents: &str,
+    transfers: &[BatchTransfer],
+) -> Result<std::collections::HashMap<usize, ReportEntry>, String> {
+    let mut report = std::collections::HashMap::new();
+    for (index, line) in contents.lines().enumerate().skip(1) {
+        let report_line_number = index + 1;
+        let fields = line.splitn(7, ',').collect::<Vec<_>>();
+        let (line_number, receiver_id, status, signed_transaction) = match fields.as_slice() {
+            [line_number, receiver_id, _amount, status, _transaction_hash, _error, signed_transaction] => {
+                (
+                    line_number
+                        .parse::<usize>()
+                        .map_err(|_| format!("line {} is invalid", report_line_number))?,
+                    receiver_id.to_string(),
+                    status
+                        .parse::<TransferStatus>()
+                        .map_err(|err| format!("line {}: {}", report_line_number, err))?,
+                    *signed_transaction,
+                )
+            }
+            _ => return Err(format!("line {} is invalid", report_line_number)),
+        };
+        match transfers
+            .iter()
+            .find(|transfer| transfer.line_number == line_number)
+        {
+            Some(transfer) if transfer.receiver_id.to_string() == receiver_id => {}
+            _ => {
+                return Err(format!(
+                    "it does not match the batch file, line {} of the batch file is not a transfer to <{}>",
+                    line_number, receiver_id
+                ))
+            }
+        }
+        let signed_transaction = if status == TransferStatus::Pending {
+            match signed_transaction.parse::<crate::common::SignedTransactionAsBase64>() {
+                Ok(signed_transaction) => Some(signed_transaction.inner),
+                Err(_) => {
+                    return Err(format!(
+                        "line {}: the signed transaction of the pending transfer on line {} of the batch file is invalid, the transfer may have been sent already (check the transaction hash in the report and fix the row)",
+                        report_line_number, line_number
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+        report.insert(
+            line_number,
+            ReportEntry {
+                status,
+                signed_transaction,
+            },
+        );
+    }
+    Ok(report)
+}
+
+fn write_report_row(
+    report_file: &mut std::fs::File,
+    transfer: &BatchTransfer,
+    status: TransferStatus,
+    transaction_hash: &str,
+    error: &str,
+    signed_transaction: &str,
+) -> crate::CliResult {
+    writeln!(
+        report_file,
+        "{},{},{},{},{},{},{}",
+        transfer.line_number,
+        transfer.receiver_id,
+        transfer.amount,
+        status,
+        transaction_hash,
+        error.replace(',', ";").replace('\n', " "),
+        signed_transaction
+    )?;
+    report_file.flush()?;
+    Ok(())
+}
+
+async fn send_transfers(
+    connection_config: &crate::common::ConnectionConfig,
+    signer_id: &near_primitives::types::AccountId,
+    signer: &crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::User,
+    transfers: Vec<BatchTransfer>,
+    report: &std::collections::HashMap<usize, ReportEntry>,
+    report_file_path: &std::path::Path,
+    max_concurrency: usize,
+) -> crate::CliResult {
+    let is_new_report = !report_file_path.exists();
+    let mut report_file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(report_file_path)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to open the report: {}", err)))?;
+    if is_new_report {
+        writeln!(report_file, "{}", REPORT_HEADER)?;
+    }
+    let mut queue = std::collections::VecDeque::new();
+    // The transfers that were signed but not reported as completed are sent again as they are:
+    // if they were executed already, the network returns the same outcome.
+    let mut interrupted_transfers = vec![];
+    for transfer in transfers {
+        match report
+            .get(&transfer.line_number)
+            .and_then(|entry| entry.signed_transaction.clone())
+        {
+            Some(signed_transaction) => interrupted_transfers.push((transfer, signed_transaction)),
+            None => queue.push_back((transfer, 0)),
+        }
+    }
+    if !interrupted_transfers.is_empty() {
+        println!(
+            "\nChecking {} transfers interrupted in the previous run ...",
+            interrupted_transfers.len()
+        );
+    }
+    for chunk in interrupted_transfers.chunks(max_concurrency) {
+        let results = send_signed_transactions(
+            connection_config,
+            chunk
+                .iter()
+                .map(|(_, signed_transaction)| signed_transaction.clone())
+                .collect(),
+        )
+        .await?;
+        for ((transfer, signed_transaction), result) in chunk.iter().zip(results) {
+            match result {
+                Err(SendError::Resign(_)) => queue.push_back((transfer.clone(), 0)),
+                result => report_result(&mut report_file, transfer, signed_transaction, result)?,
+            }
+        }
+    }
+
+    let mut last_nonce = 0;
+    while !queue.is_empty() {
+        let wave = (0..std::cmp::min(max_concurrency, queue.len()))
+            .filter_map(|_| queue.pop_front())
+            .collect::<Vec<_>>();
+        let (access_key_view, block_hash) =
+            get_access_key_and_block_hash(connection_config, signer_id, &signer.public_key).await?;
+        last_nonce = std::cmp::max(last_nonce, access_key_view.nonce);
+        let mut signed_transactions = vec![];
+        for (transfer, _) in &wave {
+            last_nonce += 1;
+            let unsigned_transaction = transfer_transaction(
+                signer_id,
+                &signer.public_key,
+                transfer,
+                last_nonce,
+                block_hash,
+            );
+            let signature = signer
+                .private_key
+                .sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+            let signed_transaction = near_primitives::transaction::SignedTransaction::new(
+                signature,
+                unsigned_transaction,
+            );
+            write_report_row(
+                &mut report_file,
+                transfer,
+                TransferStatus::Pending,
+                &signed_transaction.get_hash().to_string(),
+                "",
+                &near_primitives::serialize::to_base64(
+                    signed_transaction
+                        .try_to_vec()
+                        .expect("Transaction is not expected to fail on serialization"),
+                ),
+            )?;
+            signed_transactions.push(signed_transaction);
+        }
+        println!("\nSending {} transfers ...", wave.len());
+        let results =
+            send_signed_transactions(connection_config, signed_transactions.clone()).await?;
+        for (((transfer, attempts), signed_transaction), result) in
+            wave.into_iter().zip(signed_transactions).zip(results)
+        {
+            match result {
+                Err(SendError::Resign(_)) if attempts + 1 < MAX_SIGN_ATTEMPTS => {
+                    queue.push_back((transfer, attempts + 1))
+                }
+                result => report_result(&mut report_file, &transfer, &signed_transaction, result)?,
+            }
+        }
+    }
+    print_report_summary(report_file_path)
+}
+
+fn report_result(
+    report_file: &mut std::fs::File,
+    transfer: &BatchTransfer,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+    result: Result<near_primitives::views::FinalExecutionOutcomeView, SendError>,
+) -> crate::CliResult {
+    let transaction_hash = signed_transaction.get_hash().to_string();
+    match result {
+        Ok(transaction_info) => match transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+                println!(
+                    "Line {}: the transfer of {} to <{}> failed: {}",
+                    transfer.line_number, transfer.amount, transfer.receiver_id, tx_execution_error
+                );
+                write_report_row(
+                    report_file,
+                    transfer,
+                    TransferStatus::Failure,
+                    &transaction_hash,
+                    &tx_execution_error.to_string(),
+                    "",
+                )
+            }
+            _ => {
+                println!(
+                    "Line {}: {} has been transferred to <{}> (transaction {})",
+                    transfer.line_number,
+                    transfer.amount,
+                    transfer.receiver_id,
+                    transaction_info.transaction_outcome.id
+                );
+                write_report_row(
+                    report_file,
+                    transfer,
+                    TransferStatus::Success,
+                    &transaction_info.transaction_outcome.id.to_string(),
+                    "",
+                    "",
+                )
+            }
+        },
+        // The transaction was never executed, so it is signed again when the batch is resumed.
+        Err(SendError::Resign(error)) => {
+            println!(
+                "Line {}: the transfer of {} to <{}> was not sent, resume the batch to send it again: {}",
+                transfer.line_number, transfer.amount, transfer.receiver_id, error
+            );
+            write_report_row(
+                report_file,
+                transfer,
+                TransferStatus::NotSent,
+                "",
+                &error,
+                "",
+            )
+        }
+        Err(SendError::Failed(error)) => {
+            println!(
+                "Line {}: the transfer of {} to <{}> was not sent: {}",
+                transfer.line_number, transfer.amount, transfer.receiver_id, error
+            );
+            write_report_row(
+                report_file,
+                transfer,
+                TransferStatus::Failure,
+                "",
+                &error,
+                "",
+            )
+        }
+    }
+}
+
+fn print_report_summary(report_file_path: &std::path::Path) -> crate::CliResult {
+    let contents = std::fs::read_to_string(report_file_path)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to read the report: {}", err)))?;
+    let mut statuses = std::collections::HashMap::new();
+    for line in contents.lines().skip(1) {
+        let fields = line.splitn(7, ',').collect::<Vec<_>>();
+        if let [line_number, _, _, status, ..] = fields.as_slice() {
+            statuses.insert(line_number.to_string(), status.to_string());
+        }
+    }
+    let count = |status: TransferStatus| {
+        statuses
+            .values()
+            .filter(|value| **value == status.to_string())
+            .count()
+    };
+    println!(
+        "\nSucceeded: {}, failed: {}, not sent: {}, pending: {}. The report is saved in <{}>",
+        count(TransferStatus::Success),
+        count(TransferStatus::Failure),
+        count(TransferStatus::NotSent),
+        count(TransferStatus::Pending),
+        report_file_path.display()
+    );
+    Ok(())
+}
+
+fn transfer_transaction(
+    signer_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    transfer: &BatchTransfer,
+    nonce: u64,
+    block_hash: near_primitives::hash::CryptoHash,
+) -> near_primitives::transaction::Transaction {
+    near_primitives::transaction::Transaction {
+        signer_id: signer_id.clone(),
+        public_key: public_key.clone(),
+        nonce,
+        receiver_id: transfer.receiver_id.clone(),
+        block_hash,
+        actions: vec![near_primitives::transaction::Action::Transfer(
+            near_primitives::transaction::TransferAction {
+                deposit: transfer.amount.to_yoctonear(),
+            },
+        )],
+    }
+}
+
+async fn get_access_key_and_block_hash(
+    connection_config: &crate::common::ConnectionConfig,
+    signer_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<(
+    near_primitives::views::AccessKeyView,
+    near_primitives::hash::CryptoHash,
+)> {
+    let online_signer_access_key_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: signer_id.clone(),
+                    public_key: public_key.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch public key information for nonce: {:?}",
+                    err
+                ))
+            })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view) =
+        online_signer_access_key_response.kind
+    {
+        Ok((
+            access_key_view,
+            online_signer_access_key_response.block_hash,
+        ))
+    } else {
+        Err(color_eyre::Report::msg("Error current_nonce".to_string()))
+    }
+}
+
+/// Sends the transactions at the same time and waits for all of them.
+async fn send_signed_transactions(
+    connection_config: &crate::common::ConnectionConfig,
+    signed_transactions: Vec<near_primitives::transaction::SignedTransaction>,
+) -> color_eyre::eyre::Result<
+    Vec<Result<near_primitives::views::FinalExecutionOutcomeView, SendError>>,
+> {
+    let handles = signed_transactions
+        .into_iter()
+        .map(|signed_transaction| {
+            actix::spawn(send_signed_transaction(
+                connection_config.rpc_url(),
+                signed_transaction,
+            ))
+        })
+        .collect::<Vec<_>>();
+    let mut results = vec![];
+    for handle in handles {
+        results.push(handle.await.map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to send the transaction: {}", err))
+        })?);
+    }
+    Ok(results)
+}
+
+async fn send_signed_transaction(
+    rpc_url: url::Url,
+    signed_transaction: near_primitives::transaction::SignedTransaction,
+) -> Result<near_primitives::views::FinalExecutionOutcomeView, SendError> {
+    let json_rcp_client = near_jsonrpc_client::JsonRpcClient::connect(rpc_url.as_str());
+    loop {
+        let transaction_info_result = json_rcp_client
+            .call(
+                near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                    signed_transaction: signed_transaction.clone(),
+                },
+            )
+            .await;
+        match transaction_info_result {
+            Ok(response) => return Ok(response),
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::InvalidTransaction {
+                        context,
+                    },
+                ),
+            )) => {
+                let resign = matches!(
+                    context,
+                    near_primitives::errors::InvalidTxError::InvalidNonce { .. }
+                        | near_primitives::errors::InvalidTxError::Expired
+                );
+                let error = crate::common::handler_invalid_tx_error(context);
+                return Err(if resign {
+                    SendError::Resign(error)
+                } else {
+                    SendError::Failed(error)
+                });
+            }
+            Err(err) => match crate::common::rpc_transaction_error(err) {
+                Ok(_) => actix::clock::sleep(std::time::Duration::from_millis(100)).await,
+                Err(report) => return Err(SendError::Failed(report.to_string())),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_batch_file_rows() {
+        let transfers = parse_batch_file(
+            "receiver,amount\n# payouts\nalice.testnet, 1.5\n\nbob.testnet,100 yoctoNEAR\n",
+        )
+        .unwrap();
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].line_number, 3);
+        assert_eq!(transfers[0].receiver_id.to_string(), "alice.testnet");
+        assert_eq!(
+            transfers[0].amount.to_yoctonear(),
+            1_500_000_000_000_000_000_000_000
+        );
+        assert_eq!(transfers[1].line_number, 5);
+        assert_eq!(transfers[1].amount.to_yoctonear(), 100);
+    }
+
+    #[test]
+    fn parse_batch_file_reports_all_errors() {
+        let errors =
+            parse_batch_file("Alice!,1\nbob.testnet,0\ncarol.testnet\ndave.testnet,1 ETH\n")
+                .unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert!(errors[0].starts_with("line 1:"));
+        assert!(errors[3].starts_with("line 4:"));
+        assert!(parse_batch_file("# nothing\n").is_err());
+    }
+
+    fn signed_transaction_base64(nonce: u64) -> String {
+        let signed_transaction = near_primitives::transaction::SignedTransaction::new(
+            near_crypto::Signature::empty(near_crypto::KeyType::ED25519),
+            near_primitives::transaction::Transaction {
+                signer_id: "sender.testnet".parse().unwrap(),
+                public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                nonce,
+                receiver_id: "alice.testnet".parse().unwrap(),
+                block_hash: Default::default(),
+                actions: vec![],
+            },
+        );
+        near_primitives::serialize::to_base64(signed_transaction.try_to_vec().unwrap())
+    }
+
+    #[test]
+    fn parse_report_last_row_wins() {
+        let transfers = parse_batch_file("alice.testnet,1\nbob.testnet,2\n").unwrap();
+        let report = parse_report(
+            &format!(
+                "{}\n1,alice.testnet,1 NEAR,pending,hash,,{}\n1,alice.testnet,1 NEAR,success,hash,,\n2,bob.testnet,2 NEAR,pending,hash,,{}\n",
+                REPORT_HEADER,
+                signed_transaction_base64(1),
+                signed_transaction_base64(2)
+            ),
+            &transfers,
+        )
+        .unwrap();
+        assert_eq!(report[&1].status, TransferStatus::Success);
+        assert!(report[&1].signed_transaction.is_none());
+        assert_eq!(report[&2].status, TransferStatus::Pending);
+        assert_eq!(
+            report[&2]
+                .signed_transaction
+                .as_ref()
+                .unwrap()
+                .transaction
+                .nonce,
+            2
+        );
+    }
+
+    #[test]
+    fn parse_report_not_sent_is_not_completed() {
+        let transfers = parse_batch_file("alice.testnet,1\n").unwrap();
+        let report = parse_report(
+            &format!(
+                "{}\n1,alice.testnet,1 NEAR,pending,hash,,{}\n1,alice.testnet,1 NEAR,not_sent,,invalid nonce,\n",
+                REPORT_HEADER,
+                signed_transaction_base64(1)
+            ),
+            &transfers,
+        )
+        .unwrap();
+        assert_eq!(report[&1].status, TransferStatus::NotSent);
+        assert!(!report[&1].status.is_completed());
+        assert!(report[&1].signed_transaction.is_none());
+    }
+
+    #[test]
+    fn parse_report_rejects_a_receiver_mismatch() {
+        let transfers = parse_batch_file("alice.testnet,1\n").unwrap();
+        assert!(parse_report(
+            &format!("{}\n1,bob.testnet,1 NEAR,success,hash,,\n", REPORT_HEADER),
+            &transfers
+        )
+        .is_err());
+        assert!(parse_report(
+            &format!("{}\n2,alice.testnet,1 NEAR,success,hash,,\n", REPORT_HEADER),
+            &transfers
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parse_report_rejects_malformed_rows() {
+        let transfers = parse_batch_file("alice.testnet,1\n").unwrap();
+        for row in &[
+            "1,alice.testnet,1 NEAR,success",
+            "one,alice.testnet,1 NEAR,success,hash,,",
+            "1,alice.testnet,1 NEAR,sent,hash,,",
+            "1,alice.testnet,1 NEAR,pending,hash,,",
+            "1,alice.testnet,1 NEAR,pending,hash,,not-a-transaction",
+        ] {
+            assert!(
+                parse_report(&format!("{}\n{}\n", REPORT_HEADER, row), &transfers).is_err(),
+                "{}",
+                row
+            );
+        }
+    }
+}
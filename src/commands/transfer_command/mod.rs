@@ -1,5 +1,6 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod batch;
 pub mod operation_mode;
 mod receiver;
 mod sender;
@@ -1,4 +1,5 @@
 use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(input_context = super::operation_mode::TransferCommandNetworkContext)]
@@ -6,9 +7,44 @@ use dialoguer::Input;
 pub struct Sender {
     #[interactive_clap(skip_default_from_cli)]
     pub sender_account_id: crate::types::account_id::AccountId,
-    #[interactive_clap(named_arg)]
-    ///Specify a receiver
-    pub receiver: super::receiver::Receiver,
+    #[interactive_clap(subcommand)]
+    pub receiver_selection: ReceiverSelection,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = crate::common::SignerContext)]
+///Do you want to transfer to one receiver or to many receivers from a file?
+pub enum ReceiverSelection {
+    #[strum_discriminants(strum(message = "Transfer to one receiver"))]
+    /// Specify a receiver
+    Receiver(super::receiver::Receiver),
+    #[strum_discriminants(strum(
+        message = "Transfer to the receivers listed in a CSV file (signed with a key from the keychain)"
+    ))]
+    /// Transfer to the receivers listed in a CSV file (receiver,amount on each line), signed with a full access key from the keychain
+    Batch(super::batch::Batch),
+}
+
+impl ReceiverSelection {
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        match self {
+            Self::Receiver(receiver) => {
+                receiver
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Self::Batch(batch) => {
+                batch
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
 }
 
 struct SenderContext {
@@ -91,7 +127,7 @@ impl Sender {
             signer_id: self.sender_account_id.clone().into(),
             ..prepopulated_unsigned_transaction
         };
-        self.receiver
+        self.receiver_selection
             .process(unsigned_transaction, network_connection_config)
             .await
     }
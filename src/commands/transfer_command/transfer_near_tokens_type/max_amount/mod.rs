@@ -0,0 +1,43 @@
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct TransferMaxAmountAction {
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl TransferMaxAmountAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let connection_config = network_connection_config.clone().ok_or_else(|| {
+            color_eyre::Report::msg(
+                "The available balance can only be transferred in online mode".to_string(),
+            )
+        })?;
+        let amount = crate::common::get_max_transfer_amount(
+            &connection_config,
+            &prepopulated_unsigned_transaction.signer_id,
+            &prepopulated_unsigned_transaction.receiver_id,
+        )
+        .await?;
+        if amount.to_yoctonear() == 0 {
+            return Err(color_eyre::Report::msg(format!(
+                "<{}> has nothing available for transfer",
+                prepopulated_unsigned_transaction.signer_id
+            )));
+        }
+        println!(
+            "\n<{}> has {} available for transfer.",
+            prepopulated_unsigned_transaction.signer_id, amount
+        );
+        super::TransferNEARTokensAction {
+            amount: crate::common::TransferAmount::from_unchecked(amount),
+            sign_option: self.sign_option,
+        }
+        .process(prepopulated_unsigned_transaction, network_connection_config)
+        .await
+    }
+}
@@ -1,12 +1,23 @@
 use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
-use strum::EnumDiscriminants;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod max_amount;
+mod top_up;
 
 #[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
 #[interactive_clap(context = crate::common::SignerContext)]
-#[interactive_clap(disable_strum_discriminants)]
+///How much do you want to transfer?
 pub enum Transfer {
+    #[strum_discriminants(strum(message = "Enter an amount to transfer"))]
     /// Enter an amount to transfer
     Amount(TransferNEARTokensAction),
+    #[strum_discriminants(strum(message = "Transfer all the available balance"))]
+    /// Transfer all the available balance (the balance minus the storage stake and the exact transaction fee)
+    Max(self::max_amount::TransferMaxAmountAction),
+    #[strum_discriminants(strum(message = "Top up the receiver to a target balance"))]
+    /// Transfer the difference between a target balance and the balance of the receiver
+    TopUp(self::top_up::TransferTopUpAction),
 }
 
 impl Transfer {
@@ -21,6 +32,16 @@ impl Transfer {
                     .process(prepopulated_unsigned_transaction, network_connection_config)
                     .await
             }
+            Transfer::Max(transfer_max_amount_action) => {
+                transfer_max_amount_action
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Transfer::TopUp(transfer_top_up_action) => {
+                transfer_top_up_action
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
         }
     }
 }
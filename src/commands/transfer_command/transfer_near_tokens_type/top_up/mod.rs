@@ -0,0 +1,87 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct TransferTopUpAction {
+    pub target_balance: crate::common::NearBalance,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl TransferTopUpAction {
+    fn input_target_balance(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
+            .with_prompt("What balance should the receiver have after the transfer? (example: 10NEAR or 0.5near)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let connection_config = network_connection_config.clone().ok_or_else(|| {
+            color_eyre::Report::msg("The receiver can only be topped up in online mode".to_string())
+        })?;
+        let receiver_id = &prepopulated_unsigned_transaction.receiver_id;
+        let receiver_balance = match crate::common::get_account_view_if_exists(
+            &connection_config,
+            receiver_id.clone(),
+        )
+        .await?
+        {
+            Some(account_view) => account_view.amount,
+            // An implicit account is created by the first transfer to it.
+            None if crate::common::is_64_len_hex(receiver_id) => 0,
+            None => {
+                return Err(color_eyre::Report::msg(format!(
+                    "The receiver account <{}> does not exist",
+                    receiver_id
+                )))
+            }
+        };
+        if receiver_balance >= self.target_balance.to_yoctonear() {
+            println!(
+                "\n<{}> already has {}, there is nothing to transfer.",
+                receiver_id,
+                crate::common::NearBalance::from_yoctonear(receiver_balance)
+            );
+            return Ok(());
+        }
+        let amount = crate::common::NearBalance::from_yoctonear(
+            self.target_balance.to_yoctonear() - receiver_balance,
+        );
+        let max_transfer_amount = crate::common::get_max_transfer_amount(
+            &connection_config,
+            &prepopulated_unsigned_transaction.signer_id,
+            receiver_id,
+        )
+        .await?;
+        if amount > max_transfer_amount {
+            return Err(color_eyre::Report::msg(format!(
+                "<{}> needs {} to reach {}, but <{}> has only {} available for transfer",
+                receiver_id,
+                amount,
+                self.target_balance,
+                prepopulated_unsigned_transaction.signer_id,
+                max_transfer_amount
+            )));
+        }
+        println!(
+            "\n<{}> has {}, {} will be transferred to reach {}.",
+            receiver_id,
+            crate::common::NearBalance::from_yoctonear(receiver_balance),
+            amount,
+            self.target_balance
+        );
+        super::TransferNEARTokensAction {
+            amount: crate::common::TransferAmount::from_unchecked(amount),
+            sign_option: self.sign_option,
+        }
+        .process(prepopulated_unsigned_transaction, network_connection_config)
+        .await
+    }
+}
@@ -0,0 +1,149 @@
+use dialoguer::Input;
+
+/// Compare two contract state snapshots (saved with `view contract-state --export-to`).
+/// The snapshots can be taken at different blocks or for different accounts.
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliDiffContractStateSnapshots {
+    old_snapshot_file: Option<std::path::PathBuf>,
+    new_snapshot_file: Option<std::path::PathBuf>,
+    #[clap(long)]
+    key_format: Option<crate::types::state_data_format::StateDataFormat>,
+    #[clap(long)]
+    value_format: Option<crate::types::state_data_format::StateDataFormat>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffContractStateSnapshots {
+    old_snapshot_file: std::path::PathBuf,
+    new_snapshot_file: std::path::PathBuf,
+    key_format: crate::types::state_data_format::StateDataFormat,
+    value_format: crate::types::state_data_format::StateDataFormat,
+}
+
+impl CliDiffContractStateSnapshots {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(value_format) = &self.value_format {
+            args.push_front(value_format.to_string());
+            args.push_front("--value-format".to_owned())
+        }
+        if let Some(key_format) = &self.key_format {
+            args.push_front(key_format.to_string());
+            args.push_front("--key-format".to_owned())
+        }
+        if let Some(new_snapshot_file) = &self.new_snapshot_file {
+            args.push_front(new_snapshot_file.as_path().display().to_string());
+        }
+        if let Some(old_snapshot_file) = &self.old_snapshot_file {
+            args.push_front(old_snapshot_file.as_path().display().to_string());
+        }
+        args
+    }
+}
+
+impl From<DiffContractStateSnapshots> for CliDiffContractStateSnapshots {
+    fn from(diff_contract_state_snapshots: DiffContractStateSnapshots) -> Self {
+        Self {
+            old_snapshot_file: Some(diff_contract_state_snapshots.old_snapshot_file),
+            new_snapshot_file: Some(diff_contract_state_snapshots.new_snapshot_file),
+            key_format: Some(diff_contract_state_snapshots.key_format),
+            value_format: Some(diff_contract_state_snapshots.value_format),
+        }
+    }
+}
+
+impl From<CliDiffContractStateSnapshots> for DiffContractStateSnapshots {
+    fn from(item: CliDiffContractStateSnapshots) -> Self {
+        let old_snapshot_file = match item.old_snapshot_file {
+            Some(old_snapshot_file) => old_snapshot_file,
+            None => DiffContractStateSnapshots::input_snapshot_file(
+                "What is the file location of the old (base) snapshot?",
+            ),
+        };
+        let new_snapshot_file = match item.new_snapshot_file {
+            Some(new_snapshot_file) => new_snapshot_file,
+            None => DiffContractStateSnapshots::input_snapshot_file(
+                "What is the file location of the new snapshot?",
+            ),
+        };
+        Self {
+            old_snapshot_file,
+            new_snapshot_file,
+            key_format: item.key_format.unwrap_or_default(),
+            value_format: item.value_format.unwrap_or_default(),
+        }
+    }
+}
+
+impl DiffContractStateSnapshots {
+    fn input_snapshot_file(prompt: &str) -> std::path::PathBuf {
+        let input_file_path: String = Input::new().with_prompt(prompt).interact_text().unwrap();
+        std::path::PathBuf::from(input_file_path)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let old_snapshot = crate::common::ContractStateSnapshot::load(&self.old_snapshot_file)?;
+        let new_snapshot = crate::common::ContractStateSnapshot::load(&self.new_snapshot_file)?;
+        if old_snapshot.prefix != new_snapshot.prefix {
+            println!(
+                "WARNING! The snapshots were taken with different key prefixes, so the records missing in one of them may be just filtered out."
+            );
+        }
+        let changes = old_snapshot.diff(&new_snapshot)?;
+        println!(
+            "\nContract state changes from '{}' at block #{} ({}) to '{}' at block #{} ({}):",
+            old_snapshot.account_id,
+            old_snapshot.block_height,
+            old_snapshot.block_hash,
+            new_snapshot.account_id,
+            new_snapshot.block_height,
+            new_snapshot.block_hash
+        );
+        if changes.is_empty() {
+            println!("The contract states are identical.");
+            return Ok(());
+        }
+        let (mut added, mut removed, mut changed) = (0, 0, 0);
+        for change in changes.iter() {
+            match change {
+                crate::common::ContractStateChange::Added { key, value } => {
+                    added += 1;
+                    println!(
+                        "+ {}\n    {}",
+                        self.key_format.to_display_string(key),
+                        self.value_format.to_display_string(value)
+                    );
+                }
+                crate::common::ContractStateChange::Removed { key, value } => {
+                    removed += 1;
+                    println!(
+                        "- {}\n    {}",
+                        self.key_format.to_display_string(key),
+                        self.value_format.to_display_string(value)
+                    );
+                }
+                crate::common::ContractStateChange::Changed {
+                    key,
+                    old_value,
+                    new_value,
+                } => {
+                    changed += 1;
+                    println!(
+                        "~ {}\n    {}\n -> {}",
+                        self.key_format.to_display_string(key),
+                        self.value_format.to_display_string(old_value),
+                        self.value_format.to_display_string(new_value)
+                    );
+                }
+            }
+        }
+        println!(
+            "\n{} added, {} removed, {} changed, {} unchanged",
+            added,
+            removed,
+            changed,
+            old_snapshot.values.len() - removed - changed
+        );
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod combine_transaction_subcommand_with_signature;
+mod diff_contract_state_snapshots;
 pub mod generate_keypair_subcommand;
 #[cfg(feature = "ledger")]
 mod ledger_publickey_subcommand;
@@ -48,6 +49,8 @@ pub enum CliUtil {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     /// Send signed transaction
     SendSignedTransaction(self::send_signed_transaction::operation_mode::CliOperationMode),
+    /// Compare two contract state snapshots
+    DiffContractStateSnapshots(self::diff_contract_state_snapshots::CliDiffContractStateSnapshots),
 }
 
 #[derive(Debug, Clone, EnumDiscriminants)]
@@ -75,6 +78,8 @@ pub enum Util {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     #[strum_discriminants(strum(message = "Send signed transaction"))]
     SendSignedTransaction(self::send_signed_transaction::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Compare two contract state snapshots"))]
+    DiffContractStateSnapshots(self::diff_contract_state_snapshots::DiffContractStateSnapshots),
 }
 
 impl interactive_clap::ToCli for Util {
@@ -121,6 +126,11 @@ impl CliUtil {
                 args.push_front("send-signed-transaction".to_owned());
                 args
             }
+            Self::DiffContractStateSnapshots(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("diff-contract-state-snapshots".to_owned());
+                args
+            }
         }
     }
 }
@@ -147,6 +157,9 @@ impl From<Util> for CliUtil {
             Util::SendSignedTransaction(operation_mode) => {
                 Self::SendSignedTransaction(operation_mode.into())
             }
+            Util::DiffContractStateSnapshots(diff_contract_state_snapshots) => {
+                Self::DiffContractStateSnapshots(diff_contract_state_snapshots.into())
+            }
         }
     }
 }
@@ -192,6 +205,9 @@ impl Util {
             Some(CliUtil::SendSignedTransaction(cli_operation_mode)) => {
                 Ok(Util::SendSignedTransaction(cli_operation_mode.into()))
             }
+            Some(CliUtil::DiffContractStateSnapshots(cli_diff_contract_state_snapshots)) => Ok(
+                Util::DiffContractStateSnapshots(cli_diff_contract_state_snapshots.into()),
+            ),
             None => Self::choose_variant(context),
         }
     }
@@ -235,6 +251,9 @@ impl Util {
             UtilDiscriminants::SendSignedTransaction => {
                 CliUtil::SendSignedTransaction(Default::default())
             }
+            UtilDiscriminants::DiffContractStateSnapshots => {
+                CliUtil::DiffContractStateSnapshots(Default::default())
+            }
         };
         Ok(Self::from_cli(Some(cli_util), context)?)
     }
@@ -254,6 +273,9 @@ impl Util {
             #[cfg(feature = "ledger")]
             Self::LedgerPublicKey(ledger_publickey) => ledger_publickey.process().await,
             Self::SendSignedTransaction(operation_mode) => operation_mode.process().await,
+            Self::DiffContractStateSnapshots(diff_contract_state_snapshots) => {
+                diff_contract_state_snapshots.process().await
+            }
         }
     }
 }
@@ -62,26 +62,17 @@ impl Transaction {
         self,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
-        println!("Transaction sent ...");
-        let json_rcp_client = near_jsonrpc_client::JsonRpcClient::connect(
-            network_connection_config.rpc_url().as_str(),
-        );
-        let transaction_info = loop {
-            let transaction_info_result = json_rcp_client
-                .call(near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
-                    signed_transaction: self.signed_transaction.clone()
-                })
-                .await;
-            match transaction_info_result {
-                Ok(response) => {
-                    break response;
-                }
-                Err(err) => match crate::common::rpc_transaction_error(err) {
-                    Ok(_) => actix::clock::sleep(std::time::Duration::from_millis(100)).await,
-                    Err(report) => return color_eyre::eyre::Result::Err(report),
-                },
-            };
-        };
+        // A transaction signed with `display` skips the safety policy, so it is checked here.
+        crate::commands::construct_transaction_command::sign_transaction::safety_policy::SafetyPolicy::load(
+            &network_connection_config,
+        )?
+        .check(&self.signed_transaction.transaction)?;
+        let transaction_info =
+            crate::commands::construct_transaction_command::sign_transaction::send_transaction(
+                &network_connection_config,
+                self.signed_transaction,
+            )
+            .await?;
         crate::common::print_transaction_status(transaction_info, Some(network_connection_config));
         Ok(())
     }
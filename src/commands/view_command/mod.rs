@@ -1,11 +1,19 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod view_access_key_audit;
 mod view_account;
+mod view_account_changes;
+mod view_block;
+mod view_chunk;
 mod view_contract_code;
 mod view_contract_state;
+mod view_network_status;
 mod view_nonce;
+mod view_protocol_config;
+mod view_receipt;
 mod view_recent_block_hash;
 mod view_transaction_status;
+mod view_validators;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = ())]
@@ -15,8 +23,8 @@ pub struct ViewQueryRequest {
 }
 
 impl ViewQueryRequest {
-    pub async fn process(self) -> crate::CliResult {
-        self.query.process().await
+    pub async fn process(self, output_format: crate::common::OutputFormat) -> crate::CliResult {
+        self.query.process(output_format).await
     }
 }
 
@@ -43,10 +51,34 @@ pub enum QueryRequest {
     #[strum_discriminants(strum(message = "View recent block hash for this network"))]
     /// View recent block hash for this network
     RecentBlockHash(self::view_recent_block_hash::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View validators and epoch information"))]
+    /// View current and next validators, proposals, kicked out validators and the seat price
+    Validators(self::view_validators::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View a block"))]
+    /// View a block header or the transactions and receipts in a block
+    Block(self::view_block::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View a chunk"))]
+    /// View the transactions and receipts in a chunk
+    Chunk(self::view_chunk::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View a receipt"))]
+    /// View a receipt by its ID
+    Receipt(self::view_receipt::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the network status"))]
+    /// View the chain ID, protocol version, latest block, sync state and validators of the network
+    NetworkStatus(self::view_network_status::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the protocol config"))]
+    /// View the protocol config: storage price, limits and fees
+    ProtocolConfig(self::view_protocol_config::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the history of account changes"))]
+    /// View balance, access key, contract code and data changes of accounts in a range of blocks
+    AccountChanges(self::view_account_changes::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Audit the access keys of accounts"))]
+    /// Compare the access keys on chain with the keychain: unknown full access keys, removed local keys, exhausted allowances and unused keys
+    AccessKeyAudit(self::view_access_key_audit::operation_mode::OperationMode),
 }
 
 impl QueryRequest {
-    pub async fn process(self) -> crate::CliResult {
+    pub async fn process(self, output_format: crate::common::OutputFormat) -> crate::CliResult {
         match self {
             QueryRequest::AccountSummary(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractCode(operation_mode) => operation_mode.process().await,
@@ -54,6 +86,22 @@ impl QueryRequest {
             QueryRequest::Transaction(operation_mode) => operation_mode.process().await,
             QueryRequest::Nonce(operation_mode) => operation_mode.process().await,
             QueryRequest::RecentBlockHash(operation_mode) => operation_mode.process().await,
+            QueryRequest::Validators(operation_mode) => operation_mode.process(output_format).await,
+            QueryRequest::Block(operation_mode) => operation_mode.process(output_format).await,
+            QueryRequest::Chunk(operation_mode) => operation_mode.process(output_format).await,
+            QueryRequest::Receipt(operation_mode) => operation_mode.process(output_format).await,
+            QueryRequest::NetworkStatus(operation_mode) => {
+                operation_mode.process(output_format).await
+            }
+            QueryRequest::ProtocolConfig(operation_mode) => {
+                operation_mode.process(output_format).await
+            }
+            QueryRequest::AccountChanges(operation_mode) => {
+                operation_mode.process(output_format).await
+            }
+            QueryRequest::AccessKeyAudit(operation_mode) => {
+                operation_mode.process(output_format).await
+            }
         }
     }
 }
@@ -0,0 +1,532 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::NearBalance;
+
+/// The nonce of a new access key is the height of the block it was added in multiplied by this
+/// number, so a key that has never been used has a nonce divisible by it.
+const ACCESS_KEY_NONCE_RANGE_MULTIPLIER: u64 = 1_000_000;
+
+/// The prepaid gas of a typical function call; a function call key whose allowance does not
+/// cover its fee is considered exhausted.
+const TYPICAL_FUNCTION_CALL_GAS: u64 = 30_000_000_000_000;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccessKeyAuditCommandNetworkContext)]
+///Which accounts do you want to audit?
+pub enum AuditAccounts {
+    #[strum_discriminants(strum(message = "All the accounts in the keychain for this network"))]
+    /// Audit all the accounts in the keychain for this network
+    Keychain,
+    #[strum_discriminants(strum(message = "The accounts from a list"))]
+    /// Audit the accounts from a comma-separated list
+    AccountList(AccountList),
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccessKeyAuditCommandNetworkContext)]
+pub struct AccountList {
+    account_ids: crate::types::vec_string::VecString,
+}
+
+impl AccountList {
+    fn input_account_ids(
+        _context: &super::operation_mode::online_mode::select_server::ViewAccessKeyAuditCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::vec_string::VecString> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter a comma-separated list of accounts (example: volodymyr.testnet, 21.volodymyr.testnet)")
+            .interact_text()?)
+    }
+}
+
+/// An access key from a file in the keychain.
+struct LocalAccessKey {
+    public_key: near_crypto::PublicKey,
+    file_path: std::path::PathBuf,
+}
+
+impl AuditAccounts {
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        let mut keychain = read_keychain(&connection_config)?;
+        let account_ids: Vec<near_primitives::types::AccountId> = match self {
+            Self::Keychain => keychain.keys().cloned().collect(),
+            Self::AccountList(account_list) => {
+                let mut account_ids = vec![];
+                for account_id in account_list
+                    .account_ids
+                    .0
+                    .iter()
+                    .filter(|account_id| !account_id.is_empty())
+                {
+                    account_ids.push(account_id.parse().map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Invalid account ID <{}>: {}",
+                            account_id, err
+                        ))
+                    })?);
+                }
+                account_ids
+            }
+        };
+        if account_ids.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "There are no accounts to audit (the keychain is in ~/{})",
+                connection_config.dir_name()
+            )));
+        }
+
+        let mut exhausted_allowance_thresholds = std::collections::HashMap::new();
+        let mut audits = vec![];
+        let mut issues_count = 0;
+        for account_id in account_ids {
+            let local_access_keys = keychain.remove(&account_id).unwrap_or_default();
+            let audit = audit_account(
+                &connection_config,
+                &account_id,
+                &local_access_keys,
+                &mut exhausted_allowance_thresholds,
+            )
+            .await?;
+            issues_count += audit["issues"].as_array().map_or(0, |issues| issues.len());
+            if let crate::common::OutputFormat::Plaintext = output_format {
+                print_account_audit(&audit);
+            }
+            audits.push(audit);
+        }
+        match output_format {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "\n{} accounts audited, {} issues found.",
+                    audits.len(),
+                    issues_count
+                );
+            }
+            crate::common::OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&audits)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the key files of the network keychain: the legacy `<account_id>.json` files and the
+/// files in the `<account_id>` directories. The files that are not access keys are skipped.
+fn read_keychain(
+    connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<
+    std::collections::BTreeMap<near_primitives::types::AccountId, Vec<LocalAccessKey>>,
+> {
+    let mut keychain_path = dirs::home_dir().expect("Impossible to get your home dir!");
+    keychain_path.push(connection_config.dir_name());
+    let mut keychain: std::collections::BTreeMap<_, Vec<LocalAccessKey>> =
+        std::collections::BTreeMap::new();
+    if !keychain_path.exists() {
+        return Ok(keychain);
+    }
+    let mut file_paths = vec![];
+    for entry in keychain_path.read_dir()? {
+        let path = entry?.path();
+        if path.is_dir() {
+            for entry in path.read_dir()? {
+                file_paths.push(entry?.path());
+            }
+        } else {
+            file_paths.push(path);
+        }
+    }
+    for file_path in file_paths {
+        let is_key_file = file_path.extension().map_or(false, |ext| ext == "json")
+            && file_path.file_name().map_or(false, |file_name| {
+                file_name != crate::consts::SAFETY_POLICY_FILE_NAME
+            });
+        if !is_key_file {
+            continue;
+        }
+        match crate::commands::construct_transaction_command::sign_transaction::sign_with_keychain::read_access_key_file(&file_path) {
+            Ok(user) => keychain
+                .entry(user.account_id)
+                .or_default()
+                .push(LocalAccessKey {
+                    public_key: user.public_key,
+                    file_path,
+                }),
+            Err(err) => println!("Skipping <{}>: {}", file_path.display(), err),
+        }
+    }
+    Ok(keychain)
+}
+
+async fn audit_account(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+    local_access_keys: &[LocalAccessKey],
+    exhausted_allowance_thresholds: &mut std::collections::HashMap<bool, u128>,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let mut issues = vec![];
+    if crate::common::get_account_view_if_exists(connection_config, account_id.clone())
+        .await?
+        .is_none()
+    {
+        for local_access_key in local_access_keys {
+            issues.push(serde_json::json!({
+                "kind": "local_key_removed_on_chain",
+                "public_key": local_access_key.public_key.to_string(),
+                "file_path": local_access_key.file_path.display().to_string(),
+                "message": format!(
+                    "the account does not exist on chain, the local key {} is useless ({})",
+                    local_access_key.public_key,
+                    local_access_key.file_path.display()
+                ),
+            }));
+        }
+        return Ok(serde_json::json!({
+            "account_id": account_id.to_string(),
+            "exists": false,
+            "on_chain_keys": 0,
+            "local_keys": local_access_keys.len(),
+            "issues": issues,
+        }));
+    }
+
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view key list: {:?}",
+                    err
+                ))
+            })?;
+    let access_key_list =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+
+    // The fee of a function call only depends on whether the key calls its own account.
+    for access_key in &access_key_list.keys {
+        if let near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: Some(_),
+            receiver_id,
+            ..
+        } = &access_key.access_key.permission
+        {
+            let receiver_id = receiver_id.parse().unwrap_or_else(|_| account_id.clone());
+            let is_receiver_self = &receiver_id == account_id;
+            if !exhausted_allowance_thresholds.contains_key(&is_receiver_self) {
+                let threshold = crate::common::get_transaction_fee(
+                    connection_config,
+                    account_id,
+                    &receiver_id,
+                    &[near_primitives::transaction::Action::FunctionCall(
+                        near_primitives::transaction::FunctionCallAction {
+                            method_name: String::new(),
+                            args: vec![],
+                            gas: TYPICAL_FUNCTION_CALL_GAS,
+                            deposit: 0,
+                        },
+                    )],
+                )
+                .await?
+                .to_yoctonear();
+                exhausted_allowance_thresholds.insert(is_receiver_self, threshold);
+            }
+        }
+    }
+    let issues = find_access_key_issues(
+        account_id,
+        &access_key_list.keys,
+        local_access_keys,
+        exhausted_allowance_thresholds,
+    );
+
+    Ok(serde_json::json!({
+        "account_id": account_id.to_string(),
+        "exists": true,
+        "on_chain_keys": access_key_list.keys.len(),
+        "local_keys": local_access_keys.len(),
+        "issues": issues,
+    }))
+}
+
+/// Compares the access keys on chain with the keys in the keychain. The thresholds of exhausted
+/// allowances are keyed by whether the function call key calls its own account.
+fn find_access_key_issues(
+    account_id: &near_primitives::types::AccountId,
+    access_keys: &[near_primitives::views::AccessKeyInfoView],
+    local_access_keys: &[LocalAccessKey],
+    exhausted_allowance_thresholds: &std::collections::HashMap<bool, u128>,
+) -> Vec<serde_json::Value> {
+    let mut issues = vec![];
+    for access_key in access_keys {
+        let is_held_locally = local_access_keys
+            .iter()
+            .any(|local_access_key| local_access_key.public_key == access_key.public_key);
+        match &access_key.access_key.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => {
+                if !is_held_locally {
+                    issues.push(serde_json::json!({
+                        "kind": "full_access_key_not_held_locally",
+                        "public_key": access_key.public_key.to_string(),
+                        "message": format!(
+                            "the full access key {} is not in the keychain, make sure you know who holds it",
+                            access_key.public_key
+                        ),
+                    }));
+                }
+            }
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance: Some(allowance),
+                receiver_id,
+                ..
+            } => {
+                let is_receiver_self = *receiver_id == account_id.to_string();
+                let is_exhausted = exhausted_allowance_thresholds
+                    .get(&is_receiver_self)
+                    .map_or(false, |threshold| allowance < threshold);
+                if is_exhausted {
+                    issues.push(serde_json::json!({
+                        "kind": "function_call_key_allowance_exhausted",
+                        "public_key": access_key.public_key.to_string(),
+                        "receiver_id": receiver_id,
+                        "allowance": allowance.to_string(),
+                        "message": format!(
+                            "the function call key {} for <{}> has {} of allowance left, not enough for a {} call",
+                            access_key.public_key,
+                            receiver_id,
+                            NearBalance::from_yoctonear(*allowance),
+                            crate::common::NearGas {
+                                inner: TYPICAL_FUNCTION_CALL_GAS
+                            }
+                        ),
+                    }));
+                }
+            }
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance: None,
+                ..
+            } => {}
+        }
+        let nonce = access_key.access_key.nonce;
+        if nonce % ACCESS_KEY_NONCE_RANGE_MULTIPLIER == 0 {
+            // The keys added before the nonce ranges were introduced start with the nonce 0,
+            // the block they were added in is unknown.
+            let message = if nonce == 0 {
+                format!(
+                    "the key {} has never been used (nonce: 0)",
+                    access_key.public_key
+                )
+            } else {
+                format!(
+                    "the key {} has never been used since it was added at block #{} (nonce: {})",
+                    access_key.public_key,
+                    nonce / ACCESS_KEY_NONCE_RANGE_MULTIPLIER,
+                    nonce
+                )
+            };
+            issues.push(serde_json::json!({
+                "kind": "stale_nonce",
+                "public_key": access_key.public_key.to_string(),
+                "nonce": nonce,
+                "message": message,
+            }));
+        }
+    }
+
+    for local_access_key in local_access_keys {
+        if !access_keys
+            .iter()
+            .any(|access_key| access_key.public_key == local_access_key.public_key)
+        {
+            issues.push(serde_json::json!({
+                "kind": "local_key_removed_on_chain",
+                "public_key": local_access_key.public_key.to_string(),
+                "file_path": local_access_key.file_path.display().to_string(),
+                "message": format!(
+                    "the local key {} has been removed on chain ({})",
+                    local_access_key.public_key,
+                    local_access_key.file_path.display()
+                ),
+            }));
+        }
+    }
+    issues
+}
+
+fn print_account_audit(audit: &serde_json::Value) {
+    let issues = audit["issues"].as_array().cloned().unwrap_or_default();
+    if audit["exists"].as_bool().unwrap_or_default() {
+        println!(
+            "\n<{}>: {} access keys on chain, {} in the keychain",
+            audit["account_id"].as_str().unwrap_or_default(),
+            audit["on_chain_keys"],
+            audit["local_keys"]
+        );
+    } else {
+        println!(
+            "\n<{}>: the account does not exist, {} access keys in the keychain",
+            audit["account_id"].as_str().unwrap_or_default(),
+            audit["local_keys"]
+        );
+    }
+    if issues.is_empty() {
+        println!("  No issues found");
+    }
+    for issue in issues {
+        println!("  - {}", issue["message"].as_str().unwrap_or_default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public_key(seed: &str) -> near_crypto::PublicKey {
+        near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, seed).public_key()
+    }
+
+    fn access_key(
+        seed: &str,
+        nonce: u64,
+        permission: near_primitives::views::AccessKeyPermissionView,
+    ) -> near_primitives::views::AccessKeyInfoView {
+        near_primitives::views::AccessKeyInfoView {
+            public_key: public_key(seed),
+            access_key: near_primitives::views::AccessKeyView { nonce, permission },
+        }
+    }
+
+    fn function_call_permission(
+        allowance: u128,
+        receiver_id: &str,
+    ) -> near_primitives::views::AccessKeyPermissionView {
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: Some(allowance),
+            receiver_id: receiver_id.to_string(),
+            method_names: vec![],
+        }
+    }
+
+    fn issue_kinds(issues: &[serde_json::Value]) -> Vec<&str> {
+        issues
+            .iter()
+            .map(|issue| issue["kind"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn access_key_issues_match_local_keys() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let local_access_keys = vec![
+            LocalAccessKey {
+                public_key: public_key("local"),
+                file_path: "alice.testnet.json".into(),
+            },
+            LocalAccessKey {
+                public_key: public_key("removed"),
+                file_path: "alice.testnet/removed.json".into(),
+            },
+        ];
+        let issues = find_access_key_issues(
+            &account_id,
+            &[
+                access_key(
+                    "local",
+                    5,
+                    near_primitives::views::AccessKeyPermissionView::FullAccess,
+                ),
+                access_key(
+                    "unknown",
+                    7,
+                    near_primitives::views::AccessKeyPermissionView::FullAccess,
+                ),
+            ],
+            &local_access_keys,
+            &std::collections::HashMap::new(),
+        );
+        assert_eq!(
+            issue_kinds(&issues),
+            vec![
+                "full_access_key_not_held_locally",
+                "local_key_removed_on_chain"
+            ]
+        );
+        assert_eq!(issues[0]["public_key"], public_key("unknown").to_string());
+        assert_eq!(issues[1]["public_key"], public_key("removed").to_string());
+    }
+
+    #[test]
+    fn access_key_issues_use_the_threshold_of_the_receiver() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let thresholds = vec![(true, 100), (false, 200)].into_iter().collect();
+        let issues = find_access_key_issues(
+            &account_id,
+            &[
+                access_key("self", 1, function_call_permission(150, "alice.testnet")),
+                access_key("app", 1, function_call_permission(150, "app.testnet")),
+            ],
+            &[],
+            &thresholds,
+        );
+        assert_eq!(
+            issue_kinds(&issues),
+            vec!["function_call_key_allowance_exhausted"]
+        );
+        assert_eq!(issues[0]["receiver_id"], "app.testnet");
+    }
+
+    #[test]
+    fn access_key_issues_stale_nonce() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let full_access = near_primitives::views::AccessKeyPermissionView::FullAccess;
+        let local_access_keys = ["old", "new", "used"]
+            .iter()
+            .map(|seed| LocalAccessKey {
+                public_key: public_key(seed),
+                file_path: format!("{}.json", seed).into(),
+            })
+            .collect::<Vec<_>>();
+        let issues = find_access_key_issues(
+            &account_id,
+            &[
+                access_key("old", 0, full_access.clone()),
+                access_key(
+                    "new",
+                    42 * ACCESS_KEY_NONCE_RANGE_MULTIPLIER,
+                    full_access.clone(),
+                ),
+                access_key(
+                    "used",
+                    42 * ACCESS_KEY_NONCE_RANGE_MULTIPLIER + 1,
+                    full_access,
+                ),
+            ],
+            &local_access_keys,
+            &std::collections::HashMap::new(),
+        );
+        assert_eq!(issue_kinds(&issues), vec!["stale_nonce", "stale_nonce"]);
+        assert!(!issues[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("added at block"));
+        assert!(issues[1]["message"]
+            .as_str()
+            .unwrap()
+            .contains("added at block #42"));
+    }
+}
@@ -0,0 +1,2 @@
+mod accounts;
+pub mod operation_mode;
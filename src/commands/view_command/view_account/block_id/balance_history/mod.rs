@@ -0,0 +1,144 @@
+use dialoguer::Input;
+use std::io::Write;
+
+use crate::common::{ConnectionConfig, NearBalance};
+use crate::types::utc_date_time::{DateTimeInterval, UtcDateTime};
+use near_primitives::types::{AccountId, BlockId, BlockReference};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::commands::block_id::BlockIdContext)]
+pub struct BalanceHistory {
+    from_date_time: UtcDateTime,
+    to_date_time: UtcDateTime,
+    interval: DateTimeInterval,
+    file_path: crate::types::path_buf::PathBuf,
+}
+
+impl BalanceHistory {
+    pub fn input_from_date_time(
+        _context: &crate::commands::block_id::BlockIdContext,
+    ) -> color_eyre::eyre::Result<UtcDateTime> {
+        Ok(Input::new()
+            .with_prompt("Type the date and time in UTC of the first sample (YYYY-MM-DD HH:MM:SS)")
+            .interact_text()?)
+    }
+
+    pub fn input_to_date_time(
+        _context: &crate::commands::block_id::BlockIdContext,
+    ) -> color_eyre::eyre::Result<UtcDateTime> {
+        Ok(Input::new()
+            .with_prompt("Type the date and time in UTC of the last sample (YYYY-MM-DD HH:MM:SS)")
+            .interact_text()?)
+    }
+
+    pub fn input_interval(
+        _context: &crate::commands::block_id::BlockIdContext,
+    ) -> color_eyre::eyre::Result<DateTimeInterval> {
+        Ok(Input::new()
+            .with_prompt("Type the interval between the samples (e.g. 12h, 1d, 1month)")
+            .with_initial_text("1month")
+            .interact_text()?)
+    }
+
+    pub fn input_file_path(
+        _context: &crate::commands::block_id::BlockIdContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        Ok(Input::new()
+            .with_prompt("Where to save the balance history (CSV)?")
+            .with_initial_text("balance-history.csv")
+            .interact_text()?)
+    }
+
+    pub async fn process(self, account_id: AccountId, conf: ConnectionConfig) -> crate::CliResult {
+        if self.from_date_time > self.to_date_time {
+            return Err(color_eyre::Report::msg(format!(
+                "The first sample {} is after the last one {}",
+                self.from_date_time, self.to_date_time
+            )));
+        }
+        let mut file = std::fs::File::create(&self.file_path.0)
+            .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?;
+        writeln!(
+            file,
+            "date_time,block_height,block_hash,block_date_time,balance_near,balance_yoctonear,locked_near,locked_yoctonear,storage_usage_bytes"
+        )?;
+        // The samples only go forward in time, so the search for the next block starts from the
+        // previous one.
+        let mut previous_block = None;
+        let mut sample_index = 0;
+        loop {
+            let date_time =
+                UtcDateTime::add_interval(self.from_date_time, &self.interval, sample_index)
+                    .map_err(color_eyre::Report::msg)?;
+            if date_time > self.to_date_time {
+                break;
+            }
+            let block_view = crate::common::get_block_at_timestamp(
+                &conf,
+                date_time.timestamp_nanosec,
+                previous_block,
+            )
+            .await?;
+            // The samples before the account was created (or after it was deleted) are kept in
+            // the history with empty balances.
+            let account_view = crate::common::get_account_view_at_block_if_exists(
+                &conf,
+                account_id.clone(),
+                BlockReference::BlockId(BlockId::Hash(block_view.header.hash)),
+            )
+            .await?;
+            let account_view = match account_view {
+                Some(account_view) => account_view,
+                None => {
+                    println!(
+                        "{}: the account does not exist at block #{}",
+                        date_time, block_view.header.height
+                    );
+                    writeln!(
+                        file,
+                        "{},{},{},{},,,,,",
+                        date_time,
+                        block_view.header.height,
+                        block_view.header.hash,
+                        UtcDateTime::from_timestamp_nanosec(block_view.header.timestamp_nanosec)
+                    )?;
+                    previous_block = Some(block_view);
+                    sample_index += 1;
+                    continue;
+                }
+            };
+            println!(
+                "{}: {} (locked {}, storage {} bytes) at block #{}",
+                date_time,
+                NearBalance::from_yoctonear(account_view.amount),
+                NearBalance::from_yoctonear(account_view.locked),
+                account_view.storage_usage,
+                block_view.header.height
+            );
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{},{}",
+                date_time,
+                block_view.header.height,
+                block_view.header.hash,
+                UtcDateTime::from_timestamp_nanosec(block_view.header.timestamp_nanosec),
+                NearBalance::from_yoctonear(account_view.amount)
+                    .to_string()
+                    .trim_end_matches(" NEAR"),
+                account_view.amount,
+                NearBalance::from_yoctonear(account_view.locked)
+                    .to_string()
+                    .trim_end_matches(" NEAR"),
+                account_view.locked,
+                account_view.storage_usage
+            )?;
+            previous_block = Some(block_view);
+            sample_index += 1;
+        }
+        println!(
+            "\nThe balance history of <{}> was saved to {}",
+            account_id, self.file_path
+        );
+        Ok(())
+    }
+}
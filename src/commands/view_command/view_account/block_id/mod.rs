@@ -1,39 +1,58 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+use crate::commands::block_id::{block_id_date_time, block_id_hash, block_id_height};
 use crate::common::{display_access_key_list, display_account_info, ConnectionConfig};
 use near_primitives::types::{AccountId, Finality};
 
-mod block_id_hash;
-mod block_id_height;
+mod balance_history;
 
+/// The shared block selector with the export of the balance history on top.
 #[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
-#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccountSummaryCommandNetworkContext)]
+#[interactive_clap(context = crate::commands::block_id::BlockIdContext)]
 ///Choose Block ID
 pub enum BlockId {
     #[strum_discriminants(strum(message = "View this account at final block"))]
     /// Specify a block ID final to view this account
     AtFinalBlock,
+    #[strum_discriminants(strum(message = "View this account at near-final (doomslug) block"))]
+    /// Specify the latest block which has a doomslug finality to view this account
+    AtDoomslugBlock,
+    #[strum_discriminants(strum(message = "View this account at optimistic (latest) block"))]
+    /// Specify the latest block which may not be final yet to view this account
+    AtOptimisticBlock,
     #[strum_discriminants(strum(message = "View this account at block height"))]
     /// Specify a block ID height to view this account
-    AtBlockHeight(self::block_id_height::BlockIdHeight),
+    AtBlockHeight(block_id_height::BlockIdHeight),
     #[strum_discriminants(strum(message = "View this account at block hash"))]
     /// Specify a block ID hash to view this account
-    AtBlockHash(self::block_id_hash::BlockIdHash),
+    AtBlockHash(block_id_hash::BlockIdHash),
+    #[strum_discriminants(strum(message = "View this account at date and time"))]
+    /// Specify a date and time in UTC to view this account at the last block before it
+    AtDateTime(block_id_date_time::BlockIdDateTime),
+    #[strum_discriminants(strum(message = "Export the balance history of this account to CSV"))]
+    /// Sample the balance, locked stake and storage usage of this account at intervals and save them to CSV
+    BalanceHistory(self::balance_history::BalanceHistory),
 }
 
 impl BlockId {
     pub async fn process(self, account_id: AccountId, conf: ConnectionConfig) -> crate::CliResult {
         println!();
-        match self {
-            Self::AtBlockHeight(block_id_height) => block_id_height.process(account_id, conf).await,
-            Self::AtBlockHash(block_id_hash) => block_id_hash.process(account_id, conf).await,
-            Self::AtFinalBlock => {
-                display_account_info(account_id.clone(), &conf, Finality::Final.into()).await?;
-                display_access_key_list(account_id, &conf, Finality::Final.into()).await?;
-                Ok(())
+        let block_ref = match self {
+            Self::BalanceHistory(balance_history) => {
+                return balance_history.process(account_id, conf).await
             }
-        }
-        // Ok(())
+            Self::AtFinalBlock => Finality::Final.into(),
+            Self::AtDoomslugBlock => Finality::DoomSlug.into(),
+            Self::AtOptimisticBlock => Finality::None.into(),
+            Self::AtBlockHeight(block_id_height) => block_id_height.to_block_reference(),
+            Self::AtBlockHash(block_id_hash) => block_id_hash.to_block_reference(),
+            Self::AtDateTime(block_id_date_time) => {
+                block_id_date_time.to_block_reference(&conf).await?
+            }
+        };
+        display_account_info(account_id.clone(), &conf, block_ref.clone()).await?;
+        display_access_key_list(account_id, &conf, block_ref).await?;
+        Ok(())
     }
 }
@@ -1,13 +1,31 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccountSummaryCommandNetworkContext)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::ViewAccountSummaryCommandNetworkContext)]
+#[interactive_clap(output_context = crate::commands::block_id::BlockIdContext)]
 pub struct Sender {
     pub account_id: crate::types::account_id::AccountId,
     #[interactive_clap(subcommand)]
     selected_block_id: super::block_id::BlockId,
 }
 
+struct SenderContext;
+
+impl SenderContext {
+    pub fn from_previous_context(
+        _previous_context: super::operation_mode::online_mode::select_server::ViewAccountSummaryCommandNetworkContext,
+        _scope: &<Sender as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self
+    }
+}
+
+impl From<SenderContext> for crate::commands::block_id::BlockIdContext {
+    fn from(_item: SenderContext) -> Self {
+        Self
+    }
+}
+
 impl Sender {
     pub fn input_account_id(
         _context: &super::operation_mode::online_mode::select_server::ViewAccountSummaryCommandNetworkContext,
@@ -0,0 +1,47 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccountChangesCommandNetworkContext)]
+pub struct Accounts {
+    account_ids: crate::types::vec_string::VecString,
+    #[interactive_clap(named_arg)]
+    ///Specify the range of blocks
+    block_range: super::block_range::BlockRange,
+}
+
+impl Accounts {
+    fn input_account_ids(
+        _context: &super::operation_mode::online_mode::select_server::ViewAccountChangesCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::vec_string::VecString> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter a comma-separated list of accounts (example: volodymyr.testnet, 21.volodymyr.testnet)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        let mut account_ids: Vec<near_primitives::types::AccountId> = vec![];
+        for account_id in self
+            .account_ids
+            .0
+            .iter()
+            .filter(|account_id| !account_id.is_empty())
+        {
+            account_ids.push(account_id.parse().map_err(|err| {
+                color_eyre::Report::msg(format!("Invalid account ID <{}>: {}", account_id, err))
+            })?);
+        }
+        if account_ids.is_empty() {
+            return Err(color_eyre::Report::msg(
+                "At least one account ID is required".to_string(),
+            ));
+        }
+        self.block_range
+            .process(connection_config, account_ids, output_format)
+            .await
+    }
+}
@@ -0,0 +1,387 @@
+use dialoguer::Input;
+use near_primitives::views::{StateChangeCauseView, StateChangeKindView, StateChangeValueView};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccountChangesCommandNetworkContext)]
+pub struct BlockRange {
+    from_block_height: near_primitives::types::BlockHeight,
+    to_block_height: near_primitives::types::BlockHeight,
+}
+
+impl BlockRange {
+    fn input_from_block_height(
+        _context: &super::operation_mode::online_mode::select_server::ViewAccountChangesCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<near_primitives::types::BlockHeight> {
+        Ok(Input::new()
+            .with_prompt("Type the height of the first block of the range")
+            .interact_text()?)
+    }
+
+    fn input_to_block_height(
+        _context: &super::operation_mode::online_mode::select_server::ViewAccountChangesCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<near_primitives::types::BlockHeight> {
+        Ok(Input::new()
+            .with_prompt("Type the height of the last block of the range")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        account_ids: Vec<near_primitives::types::AccountId>,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        if self.from_block_height > self.to_block_height {
+            return Err(color_eyre::Report::msg(format!(
+                "The first block of the range #{} is after the last one #{}",
+                self.from_block_height, self.to_block_height
+            )));
+        }
+        let json_rpc_client = near_jsonrpc_client::JsonRpcClient::connect(
+            connection_config.archival_rpc_url().as_str(),
+        );
+        let mut balances: std::collections::HashMap<near_primitives::types::AccountId, u128> =
+            std::collections::HashMap::new();
+        let mut known_access_keys = std::collections::HashSet::new();
+        let mut history = vec![];
+        if let crate::common::OutputFormat::Plaintext = output_format {
+            println!(
+                "\nChanges of {} in blocks #{}..#{}:",
+                account_ids
+                    .iter()
+                    .map(|account_id| format!("<{}>", account_id))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                self.from_block_height,
+                self.to_block_height
+            );
+        }
+        for block_height in self.from_block_height..=self.to_block_height {
+            // EXPERIMENTAL_changes_in_block only lists which accounts were touched in the block,
+            // so the detailed changes are requested just for the blocks with our accounts.
+            let changes_in_block = match json_rpc_client
+                .call(
+                    near_jsonrpc_client::methods::EXPERIMENTAL_changes_in_block::RpcStateChangesInBlockRequest {
+                        block_reference: near_primitives::types::BlockReference::BlockId(
+                            near_primitives::types::BlockId::Height(block_height),
+                        ),
+                    },
+                )
+                .await
+            {
+                Ok(changes_in_block) => changes_in_block,
+                // There may be no block at this height.
+                Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                    near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                        near_jsonrpc_primitives::types::changes::RpcStateChangesError::UnknownBlock {
+                            ..
+                        },
+                    ),
+                )) => continue,
+                Err(err) => {
+                    return Err(color_eyre::Report::msg(format!(
+                        "Failed to fetch the changes in block #{}: {:?}",
+                        block_height, err
+                    )))
+                }
+            };
+            let touched = |kind_filter: fn(
+                &StateChangeKindView,
+            )
+                -> Option<&near_primitives::types::AccountId>| {
+                account_ids
+                    .iter()
+                    .filter(|account_id| {
+                        changes_in_block
+                            .changes
+                            .iter()
+                            .filter_map(kind_filter)
+                            .any(|touched_account_id| touched_account_id == *account_id)
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            };
+            let state_changes_requests = vec![
+                near_primitives::views::StateChangesRequestView::AccountChanges {
+                    account_ids: touched(|kind| match kind {
+                        StateChangeKindView::AccountTouched { account_id } => Some(account_id),
+                        _ => None,
+                    }),
+                },
+                near_primitives::views::StateChangesRequestView::AllAccessKeyChanges {
+                    account_ids: touched(|kind| match kind {
+                        StateChangeKindView::AccessKeyTouched { account_id } => Some(account_id),
+                        _ => None,
+                    }),
+                },
+                near_primitives::views::StateChangesRequestView::ContractCodeChanges {
+                    account_ids: touched(|kind| match kind {
+                        StateChangeKindView::ContractCodeTouched { account_id } => Some(account_id),
+                        _ => None,
+                    }),
+                },
+                near_primitives::views::StateChangesRequestView::DataChanges {
+                    account_ids: touched(|kind| match kind {
+                        StateChangeKindView::DataTouched { account_id } => Some(account_id),
+                        _ => None,
+                    }),
+                    key_prefix: near_primitives::types::StoreKey::from(vec![]),
+                },
+            ];
+            for state_changes_request in state_changes_requests {
+                let is_empty = match &state_changes_request {
+                    near_primitives::views::StateChangesRequestView::AccountChanges {
+                        account_ids,
+                    }
+                    | near_primitives::views::StateChangesRequestView::AllAccessKeyChanges {
+                        account_ids,
+                    }
+                    | near_primitives::views::StateChangesRequestView::ContractCodeChanges {
+                        account_ids,
+                    }
+                    | near_primitives::views::StateChangesRequestView::DataChanges {
+                        account_ids,
+                        ..
+                    } => account_ids.is_empty(),
+                    near_primitives::views::StateChangesRequestView::SingleAccessKeyChanges {
+                        ..
+                    } => true,
+                };
+                if is_empty {
+                    continue;
+                }
+                let state_changes = json_rpc_client
+                    .call(
+                        near_jsonrpc_client::methods::EXPERIMENTAL_changes::RpcStateChangesInBlockByTypeRequest {
+                            block_reference: near_primitives::types::BlockReference::BlockId(
+                                near_primitives::types::BlockId::Hash(changes_in_block.block_hash),
+                            ),
+                            state_changes_request,
+                        },
+                    )
+                    .await
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to fetch the changes in block #{}: {:?}",
+                            block_height, err
+                        ))
+                    })?;
+                for state_change in state_changes.changes {
+                    let description = describe_state_change(
+                        &connection_config,
+                        &mut balances,
+                        &mut known_access_keys,
+                        changes_in_block.block_hash,
+                        &state_change.cause,
+                        &state_change.value,
+                    )
+                    .await?;
+                    match output_format {
+                        crate::common::OutputFormat::Plaintext => println!(
+                            "  #{} <{}>: {} (cause: {})",
+                            block_height,
+                            state_change_account_id(&state_change.value),
+                            description,
+                            state_change_cause_summary(&state_change.cause)
+                        ),
+                        crate::common::OutputFormat::Json => history.push(serde_json::json!({
+                            "block_height": block_height,
+                            "block_hash": changes_in_block.block_hash,
+                            "description": description,
+                            "cause": state_change.cause,
+                            "change": state_change.value,
+                        })),
+                    }
+                }
+            }
+        }
+        if let crate::common::OutputFormat::Json = output_format {
+            println!("{}", serde_json::to_string_pretty(&history)?);
+        }
+        Ok(())
+    }
+}
+
+fn state_change_account_id(value: &StateChangeValueView) -> &near_primitives::types::AccountId {
+    match value {
+        StateChangeValueView::AccountUpdate { account_id, .. }
+        | StateChangeValueView::AccountDeletion { account_id }
+        | StateChangeValueView::AccessKeyUpdate { account_id, .. }
+        | StateChangeValueView::AccessKeyDeletion { account_id, .. }
+        | StateChangeValueView::DataUpdate { account_id, .. }
+        | StateChangeValueView::DataDeletion { account_id, .. }
+        | StateChangeValueView::ContractCodeUpdate { account_id, .. }
+        | StateChangeValueView::ContractCodeDeletion { account_id } => account_id,
+    }
+}
+
+fn state_change_cause_summary(cause: &StateChangeCauseView) -> String {
+    match cause {
+        StateChangeCauseView::TransactionProcessing { tx_hash } => {
+            format!("transaction {}", tx_hash)
+        }
+        StateChangeCauseView::ActionReceiptProcessingStarted { receipt_hash }
+        | StateChangeCauseView::ActionReceiptGasReward { receipt_hash }
+        | StateChangeCauseView::ReceiptProcessing { receipt_hash }
+        | StateChangeCauseView::PostponedReceipt { receipt_hash } => {
+            format!("receipt {}", receipt_hash)
+        }
+        cause => format!("{:?}", cause),
+    }
+}
+
+/// The block before the given one, where the state before its changes can be viewed.
+async fn previous_block_reference(
+    connection_config: &crate::common::ConnectionConfig,
+    block_hash: near_primitives::hash::CryptoHash,
+) -> color_eyre::eyre::Result<near_primitives::types::BlockReference> {
+    let block_view = crate::common::get_block(
+        connection_config,
+        near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Hash(
+            block_hash,
+        )),
+    )
+    .await?;
+    Ok(near_primitives::types::BlockReference::BlockId(
+        near_primitives::types::BlockId::Hash(block_view.header.prev_hash),
+    ))
+}
+
+fn json_string(value: impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(value) => value.to_string(),
+        Err(err) => err.to_string(),
+    }
+}
+
+async fn describe_state_change(
+    connection_config: &crate::common::ConnectionConfig,
+    balances: &mut std::collections::HashMap<near_primitives::types::AccountId, u128>,
+    known_access_keys: &mut std::collections::HashSet<(
+        near_primitives::types::AccountId,
+        near_crypto::PublicKey,
+    )>,
+    block_hash: near_primitives::hash::CryptoHash,
+    cause: &StateChangeCauseView,
+    value: &StateChangeValueView,
+) -> color_eyre::eyre::Result<String> {
+    Ok(match value {
+        StateChangeValueView::AccountUpdate {
+            account_id,
+            account,
+        } => {
+            let previous_amount = match balances.get(account_id) {
+                Some(previous_amount) => *previous_amount,
+                None => {
+                    // The first change of the account in the range is compared with its state
+                    // in the previous block (there was no account if it does not exist there).
+                    crate::common::get_account_view_at_block_if_exists(
+                        connection_config,
+                        account_id.clone(),
+                        previous_block_reference(connection_config, block_hash).await?,
+                    )
+                    .await?
+                    .map_or(0, |account_view| account_view.amount)
+                }
+            };
+            balances.insert(account_id.clone(), account.amount);
+            if account.amount > previous_amount {
+                format!(
+                    "balance {} (+{})",
+                    crate::common::NearBalance::from_yoctonear(account.amount),
+                    crate::common::NearBalance::from_yoctonear(account.amount - previous_amount)
+                )
+            } else if account.amount < previous_amount {
+                format!(
+                    "balance {} (-{})",
+                    crate::common::NearBalance::from_yoctonear(account.amount),
+                    crate::common::NearBalance::from_yoctonear(previous_amount - account.amount)
+                )
+            } else {
+                format!(
+                    "account updated, storage usage {} bytes",
+                    account.storage_usage
+                )
+            }
+        }
+        StateChangeValueView::AccountDeletion { .. } => "account deleted".to_string(),
+        StateChangeValueView::AccessKeyUpdate {
+            account_id,
+            public_key,
+            access_key,
+        } => {
+            // Nonces are incremented while the transactions are converted to receipts, keys are
+            // added while the receipts with the AddKey actions are executed and the gas refunds
+            // of function call keys are returned to their allowance with the receipts as well.
+            if let StateChangeCauseView::TransactionProcessing { .. } = cause {
+                known_access_keys.insert((account_id.clone(), public_key.clone()));
+                format!("access key {} used, nonce {}", public_key, access_key.nonce)
+            } else {
+                let is_known = known_access_keys
+                    .contains(&(account_id.clone(), public_key.clone()))
+                    || crate::common::get_access_key_view_at_block_if_exists(
+                        connection_config,
+                        account_id.clone(),
+                        public_key.clone(),
+                        previous_block_reference(connection_config, block_hash).await?,
+                    )
+                    .await?
+                    .is_some();
+                known_access_keys.insert((account_id.clone(), public_key.clone()));
+                match &access_key.permission {
+                    near_primitives::views::AccessKeyPermissionView::FullAccess if is_known => {
+                        format!("access key {} updated (full access)", public_key)
+                    }
+                    near_primitives::views::AccessKeyPermissionView::FullAccess => {
+                        format!("access key {} added (full access)", public_key)
+                    }
+                    near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                        allowance,
+                        receiver_id,
+                        ..
+                    } => {
+                        let allowance = match allowance {
+                            Some(allowance) => {
+                                crate::common::NearBalance::from_yoctonear(*allowance).to_string()
+                            }
+                            None => "unlimited".to_string(),
+                        };
+                        if is_known {
+                            format!(
+                                "access key {} updated (allowance {})",
+                                public_key, allowance
+                            )
+                        } else {
+                            format!(
+                                "access key {} added (function call on <{}>, allowance {})",
+                                public_key, receiver_id, allowance
+                            )
+                        }
+                    }
+                }
+            }
+        }
+        StateChangeValueView::AccessKeyDeletion {
+            account_id,
+            public_key,
+        } => {
+            known_access_keys.remove(&(account_id.clone(), public_key.clone()));
+            format!("access key {} deleted", public_key)
+        }
+        StateChangeValueView::DataUpdate { key, value, .. } => format!(
+            "data updated, key {} value {}",
+            json_string(key),
+            json_string(value)
+        ),
+        StateChangeValueView::DataDeletion { key, .. } => {
+            format!("data deleted, key {}", json_string(key))
+        }
+        StateChangeValueView::ContractCodeUpdate { code, .. } => format!(
+            "contract code deployed ({} bytes, hash {})",
+            code.len(),
+            near_primitives::hash::hash(code)
+        ),
+        StateChangeValueView::ContractCodeDeletion { .. } => "contract code deleted".to_string(),
+    })
+}
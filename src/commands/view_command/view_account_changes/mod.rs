@@ -0,0 +1,3 @@
+mod accounts;
+mod block_range;
+pub mod operation_mode;
@@ -0,0 +1,186 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+
+use crate::common::{ConnectionConfig, NearBalance, NearGas, OutputFormat};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::ViewBlockCommandNetworkContext)]
+#[interactive_clap(output_context = crate::commands::block_id::BlockIdContext)]
+pub struct Block {
+    pub contents: BlockContents,
+    #[interactive_clap(subcommand)]
+    selected_block_id: crate::commands::block_id::BlockId,
+}
+
+struct BlockContext;
+
+impl BlockContext {
+    pub fn from_previous_context(
+        _previous_context: super::operation_mode::online_mode::select_server::ViewBlockCommandNetworkContext,
+        _scope: &<Block as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self
+    }
+}
+
+impl From<BlockContext> for crate::commands::block_id::BlockIdContext {
+    fn from(_item: BlockContext) -> Self {
+        Self
+    }
+}
+
+impl Block {
+    fn input_contents(
+        _context: &super::operation_mode::online_mode::select_server::ViewBlockCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<BlockContents> {
+        let variants = [BlockContents::Header, BlockContents::Transactions];
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to view in the block?")
+            .items(&[
+                "The block header and its chunks",
+                "The transactions and receipts in the block",
+            ])
+            .default(0)
+            .interact()?;
+        Ok(variants[selection])
+    }
+
+    pub async fn process(
+        self,
+        connection_config: ConnectionConfig,
+        output_format: OutputFormat,
+    ) -> crate::CliResult {
+        println!();
+        display_block(
+            &connection_config,
+            self.selected_block_id
+                .to_block_reference(&connection_config)
+                .await?,
+            self.contents,
+            output_format,
+        )
+        .await
+    }
+}
+
+/// What is shown for the selected block.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockContents {
+    Header,
+    Transactions,
+}
+
+impl std::fmt::Display for BlockContents {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Header => write!(f, "header"),
+            Self::Transactions => write!(f, "transactions"),
+        }
+    }
+}
+
+impl std::str::FromStr for BlockContents {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "header" => Ok(Self::Header),
+            "transactions" => Ok(Self::Transactions),
+            _ => Err(format!(
+                "Block contents: <{}> is not supported (use header or transactions)",
+                s
+            )),
+        }
+    }
+}
+
+impl interactive_clap::ToCli for BlockContents {
+    type CliVariant = BlockContents;
+}
+
+pub async fn display_block(
+    connection_config: &ConnectionConfig,
+    block_reference: near_primitives::types::BlockReference,
+    contents: BlockContents,
+    output_format: OutputFormat,
+) -> crate::CliResult {
+    let block_view = crate::common::get_block(connection_config, block_reference).await?;
+    match contents {
+        BlockContents::Header => {
+            if let OutputFormat::Json = output_format {
+                println!("{}", serde_json::to_string_pretty(&block_view)?);
+                return Ok(());
+            }
+            print_block_header(&block_view);
+        }
+        BlockContents::Transactions => {
+            // Chunks which were not produced for this block are repeated from the previous
+            // blocks, their transactions and receipts do not belong to this block.
+            let mut chunks = vec![];
+            for chunk_header in block_view
+                .chunks
+                .iter()
+                .filter(|chunk_header| chunk_header.height_included == block_view.header.height)
+            {
+                chunks.push(
+                    crate::common::get_chunk(connection_config, chunk_header.chunk_hash).await?,
+                );
+            }
+            if let OutputFormat::Json = output_format {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "block_height": block_view.header.height,
+                        "block_hash": block_view.header.hash,
+                        "chunks": chunks,
+                    }))?
+                );
+                return Ok(());
+            }
+            println!(
+                "Block #{} ({})",
+                block_view.header.height, block_view.header.hash
+            );
+            if chunks.is_empty() {
+                println!("\nThere are no new chunks in this block.");
+            }
+            for chunk_view in chunks.iter() {
+                println!();
+                crate::common::print_chunk_contents(chunk_view);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_block_header(block_view: &near_primitives::views::BlockView) {
+    let header = &block_view.header;
+    println!("Block #{} ({})", header.height, header.hash);
+    println!("  Author:          {}", block_view.author);
+    println!("  Timestamp:       {} ns", header.timestamp_nanosec);
+    println!("  Previous block:  {}", header.prev_hash);
+    println!("  Epoch ID:        {}", header.epoch_id);
+    println!(
+        "  Gas price:       {}",
+        NearBalance::from_yoctonear(header.gas_price)
+    );
+    println!(
+        "  Total supply:    {}",
+        NearBalance::from_yoctonear(header.total_supply)
+    );
+    println!("  Protocol:        {}", header.latest_protocol_version);
+    println!("\nChunks:");
+    for chunk_header in block_view.chunks.iter() {
+        println!(
+            "  shard {}  {}  gas used {} of {}{}",
+            chunk_header.shard_id,
+            chunk_header.chunk_hash,
+            NearGas::from(chunk_header.gas_used),
+            NearGas::from(chunk_header.gas_limit),
+            if chunk_header.height_included == header.height {
+                ""
+            } else {
+                "  (missing, repeated from a previous block)"
+            }
+        );
+    }
+}
@@ -0,0 +1,2 @@
+mod block;
+pub mod operation_mode;
@@ -0,0 +1,43 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewChunkCommandNetworkContext)]
+pub struct Chunk {
+    pub chunk_hash: crate::types::crypto_hash::CryptoHash,
+}
+
+impl Chunk {
+    fn input_chunk_hash(
+        _context: &super::operation_mode::online_mode::select_server::ViewChunkCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::crypto_hash::CryptoHash> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter the hash of the chunk you need to view")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        let chunk_view =
+            crate::common::get_chunk(&connection_config, self.chunk_hash.into()).await?;
+        if let crate::common::OutputFormat::Json = output_format {
+            println!("{}", serde_json::to_string_pretty(&chunk_view)?);
+            return Ok(());
+        }
+        println!();
+        println!(
+            "Created at block height {}, included at block height {}",
+            chunk_view.header.height_created, chunk_view.header.height_included
+        );
+        println!(
+            "Gas used {} of {}",
+            crate::common::NearGas::from(chunk_view.header.gas_used),
+            crate::common::NearGas::from(chunk_view.header.gas_limit)
+        );
+        crate::common::print_chunk_contents(&chunk_view);
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod chunk;
+pub mod operation_mode;
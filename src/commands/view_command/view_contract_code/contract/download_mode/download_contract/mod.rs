@@ -1,11 +1,29 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = crate::common::SignerContext)]
+#[interactive_clap(input_context = crate::common::SignerContext)]
+#[interactive_clap(output_context = crate::commands::block_id::BlockIdContext)]
 pub struct ContractFile {
     pub file_path: crate::types::path_buf::PathBuf,
     #[interactive_clap(subcommand)]
-    pub selected_block_id: super::super::super::block_id::BlockId,
+    pub selected_block_id: crate::commands::block_id::BlockId,
+}
+
+struct ContractFileContext;
+
+impl ContractFileContext {
+    pub fn from_previous_context(
+        _previous_context: crate::common::SignerContext,
+        _scope: &<ContractFile as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self
+    }
+}
+
+impl From<ContractFileContext> for crate::commands::block_id::BlockIdContext {
+    fn from(_item: ContractFileContext) -> Self {
+        Self
+    }
 }
 
 impl ContractFile {
@@ -26,12 +44,17 @@ impl ContractFile {
         contract_id: near_primitives::types::AccountId,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
-        self.selected_block_id
-            .process(
-                contract_id,
-                network_connection_config,
-                Some(self.file_path.into()),
-            )
-            .await
+        println!();
+        let block_reference = self
+            .selected_block_id
+            .to_block_reference(&network_connection_config)
+            .await?;
+        crate::common::display_contract_code(
+            contract_id,
+            &network_connection_config,
+            block_reference,
+            crate::common::ContractCodeViewMode::Download(self.file_path.into()),
+        )
+        .await
     }
 }
@@ -2,6 +2,7 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod download_contract;
 mod hash_contract;
+mod verify_contract;
 
 #[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
@@ -14,6 +15,11 @@ pub enum DownloadMode {
     #[strum_discriminants(strum(message = "View a contract hash"))]
     /// View a contract hash
     Hash(self::hash_contract::ContractHash),
+    #[strum_discriminants(strum(
+        message = "Verify a local contract file against the deployed one"
+    ))]
+    /// Verify a local contract file against the deployed one
+    Verify(self::verify_contract::ContractVerify),
 }
 
 impl DownloadMode {
@@ -33,6 +39,11 @@ impl DownloadMode {
                     .process(contract_id, network_connection_config)
                     .await
             }
+            DownloadMode::Verify(contract_verify) => {
+                contract_verify
+                    .process(contract_id, network_connection_config)
+                    .await
+            }
         }
     }
 }
@@ -0,0 +1,58 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = crate::common::SignerContext)]
+#[interactive_clap(output_context = crate::commands::block_id::BlockIdContext)]
+pub struct ContractVerify {
+    pub file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(subcommand)]
+    pub selected_block_id: crate::commands::block_id::BlockId,
+}
+
+struct ContractVerifyContext;
+
+impl ContractVerifyContext {
+    pub fn from_previous_context(
+        _previous_context: crate::common::SignerContext,
+        _scope: &<ContractVerify as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self
+    }
+}
+
+impl From<ContractVerifyContext> for crate::commands::block_id::BlockIdContext {
+    fn from(_item: ContractVerifyContext) -> Self {
+        Self
+    }
+}
+
+impl ContractVerify {
+    fn input_file_path(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let input_file_path: String = Input::new()
+            .with_prompt("What is the file location of the locally built contract?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(input_file_path).into())
+    }
+
+    pub async fn process(
+        self,
+        contract_id: near_primitives::types::AccountId,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        println!();
+        let block_reference = self
+            .selected_block_id
+            .to_block_reference(&network_connection_config)
+            .await?;
+        crate::common::display_contract_code(
+            contract_id,
+            &network_connection_config,
+            block_reference,
+            crate::common::ContractCodeViewMode::Verify(self.file_path.into()),
+        )
+        .await
+    }
+}
@@ -1,3 +1,2 @@
-mod block_id;
 pub mod contract;
 pub mod operation_mode;
@@ -1,3 +1,2 @@
-mod block_id;
 pub mod operation_mode;
 mod sender;
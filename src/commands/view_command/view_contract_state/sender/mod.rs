@@ -1,12 +1,26 @@
-use dialoguer::Input;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext)]
 #[interactive_clap(skip_default_from_cli)]
 pub struct Sender {
     pub sender_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    pub prefix: Option<crate::types::state_key_prefix::StateKeyPrefix>,
+    #[interactive_clap(long)]
+    pub key_format: Option<crate::types::state_data_format::StateDataFormat>,
+    #[interactive_clap(long)]
+    pub value_format: Option<crate::types::state_data_format::StateDataFormat>,
+    #[interactive_clap(long)]
+    pub hide_proof: Option<bool>,
+    #[interactive_clap(long)]
+    pub offset: Option<usize>,
+    #[interactive_clap(long)]
+    pub limit: Option<usize>,
+    #[interactive_clap(long)]
+    pub export_to: Option<crate::types::path_buf::PathBuf>,
     #[interactive_clap(subcommand)]
-    selected_block_id: super::block_id::BlockId,
+    selected_block_id: crate::commands::block_id::BlockId,
 }
 
 impl Sender {
@@ -15,10 +29,12 @@ impl Sender {
         context: super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
     ) -> color_eyre::eyre::Result<Self> {
         let connection_config = context.connection_config.clone();
-        let sender_account_id = match optional_clap_variant
+        let cli_sender_account_id = optional_clap_variant
             .clone()
-            .and_then(|clap_variant| clap_variant.sender_account_id)
-        {
+            .and_then(|clap_variant| clap_variant.sender_account_id);
+        // The view options are only asked for in the interactive mode, otherwise the defaults are used
+        let is_interactive = cli_sender_account_id.is_none();
+        let sender_account_id = match cli_sender_account_id {
             Some(sender_account_id) => match crate::common::get_account_state(
                 &connection_config,
                 sender_account_id.clone().into(),
@@ -31,14 +47,70 @@ impl Sender {
             },
             None => Self::input_sender_account_id(&context)?,
         };
-        let selected_block_id: super::block_id::BlockId = match optional_clap_variant
+        let prefix = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.prefix)
+        {
+            Some(cli_prefix) => Some(cli_prefix),
+            None if is_interactive => Self::input_prefix(&context)?,
+            None => None,
+        };
+        let key_format = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.key_format)
+        {
+            Some(cli_key_format) => Some(cli_key_format),
+            None if is_interactive => Some(Self::input_key_format(&context)?),
+            None => None,
+        };
+        let value_format = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.value_format)
+        {
+            Some(cli_value_format) => Some(cli_value_format),
+            None if is_interactive => Some(Self::input_value_format(&context)?),
+            None => None,
+        };
+        let hide_proof = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.hide_proof)
+        {
+            Some(cli_hide_proof) => Some(cli_hide_proof),
+            None if is_interactive => Some(Self::input_hide_proof(&context)?),
+            None => None,
+        };
+        let offset = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.offset);
+        let limit = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.limit);
+        let export_to = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.export_to)
+        {
+            Some(cli_export_to) => Some(cli_export_to),
+            None if is_interactive => Self::input_export_to(&context)?,
+            None => None,
+        };
+        let block_id_context = crate::commands::block_id::BlockIdContext;
+        let selected_block_id: crate::commands::block_id::BlockId = match optional_clap_variant
             .and_then(|clap_variant| clap_variant.selected_block_id)
         {
-            Some(cli_block_id) => super::block_id::BlockId::from_cli(Some(cli_block_id), context)?,
-            None => super::block_id::BlockId::choose_variant(context)?,
+            Some(cli_block_id) => {
+                crate::commands::block_id::BlockId::from_cli(Some(cli_block_id), block_id_context)?
+            }
+            None => crate::commands::block_id::BlockId::choose_variant(block_id_context)?,
         };
         Ok(Self {
             sender_account_id,
+            prefix,
+            key_format,
+            value_format,
+            hide_proof,
+            offset,
+            limit,
+            export_to,
             selected_block_id,
         })
     }
@@ -63,12 +135,122 @@ impl Sender {
         }
     }
 
+    fn input_prefix(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::state_key_prefix::StateKeyPrefix>> {
+        println!();
+        let prefix: String = Input::new()
+            .with_prompt(
+                "Enter a key prefix to filter the state (e.g. STATE, hex:00 or base64:AA==; leave empty to view all keys)",
+            )
+            .allow_empty(true)
+            .interact_text()?;
+        if prefix.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(prefix.parse().map_err(color_eyre::Report::msg)?))
+        }
+    }
+
+    fn input_data_format(
+        prompt: &str,
+    ) -> color_eyre::eyre::Result<crate::types::state_data_format::StateDataFormat> {
+        let formats = ["base64", "utf8", "hex", "json", "borsh"];
+        let selected = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .items(&formats)
+            .default(0)
+            .interact()?;
+        if formats[selected] == "borsh" {
+            loop {
+                let schema: String = Input::new()
+                    .with_prompt(
+                        "Enter the borsh schema (e.g. u64, string, vec<u8>, option<u128>, (string,u128), [u8;32])",
+                    )
+                    .interact_text()?;
+                match format!("borsh:{}", schema)
+                    .parse::<crate::types::state_data_format::StateDataFormat>()
+                {
+                    Ok(format) => break Ok(format),
+                    Err(err) => println!("{}", err),
+                }
+            }
+        } else {
+            Ok(formats[selected].parse().map_err(color_eyre::Report::msg)?)
+        }
+    }
+
+    fn input_key_format(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::state_data_format::StateDataFormat> {
+        println!();
+        Self::input_data_format("How do you want to decode the keys (without the prefix)?")
+    }
+
+    fn input_value_format(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::state_data_format::StateDataFormat> {
+        println!();
+        Self::input_data_format("How do you want to decode the values?")
+    }
+
+    fn input_hide_proof(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<bool> {
+        println!();
+        let choices = [
+            "No, show only the state records",
+            "Yes, show the proof as well",
+        ];
+        let selected = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to view the state proof?")
+            .items(&choices)
+            .default(0)
+            .interact()?;
+        Ok(selected == 0)
+    }
+
+    fn input_export_to(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::path_buf::PathBuf>> {
+        println!();
+        let file_path: String = Input::new()
+            .with_prompt(
+                "Where to save a snapshot of the state (it can be compared later with `utils diff-contract-state-snapshots`; leave empty to skip)?",
+            )
+            .allow_empty(true)
+            .interact_text()?;
+        if file_path.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(std::path::PathBuf::from(file_path).into()))
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
-        self.selected_block_id
-            .process(self.sender_account_id.into(), network_connection_config)
-            .await
+        let options = crate::common::ContractStateViewOptions {
+            prefix: self.prefix.unwrap_or_default(),
+            key_format: self.key_format.unwrap_or_default(),
+            value_format: self.value_format.unwrap_or_default(),
+            hide_proof: self.hide_proof.unwrap_or(false),
+            offset: self.offset.unwrap_or(0),
+            limit: self.limit,
+            export_to: self.export_to.map(Into::into),
+        };
+        println!();
+        let block_reference = self
+            .selected_block_id
+            .to_block_reference(&network_connection_config)
+            .await?;
+        crate::common::display_contract_state(
+            self.sender_account_id.into(),
+            &network_connection_config,
+            block_reference,
+            &options,
+        )
+        .await
     }
 }
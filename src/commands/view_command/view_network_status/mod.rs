@@ -0,0 +1 @@
+pub mod operation_mode;
@@ -0,0 +1,115 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::SelectServerContext)]
+pub struct Server {}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::SelectServerContext)]
+#[interactive_clap(output_context = super::ViewNetworkStatusCommandNetworkContext)]
+pub struct CustomServer {
+    #[interactive_clap(long)]
+    pub url: crate::common::AvailableRpcServerUrl,
+}
+
+struct CustomServerContext {
+    pub url: crate::common::AvailableRpcServerUrl,
+}
+
+impl CustomServerContext {
+    fn _from_previous_context(
+        _previous_context: super::SelectServerContext,
+        scope: &<CustomServer as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            url: scope.url.clone(),
+        }
+    }
+}
+
+impl From<CustomServerContext> for super::ViewNetworkStatusCommandNetworkContext {
+    fn from(item: CustomServerContext) -> Self {
+        Self {
+            connection_config: crate::common::ConnectionConfig::from_custom_url(&item.url),
+        }
+    }
+}
+
+impl Server {
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        display_network_status(connection_config, output_format).await
+    }
+}
+
+impl CustomServer {
+    pub fn input_url(
+        _context: &super::SelectServerContext,
+    ) -> color_eyre::eyre::Result<crate::common::AvailableRpcServerUrl> {
+        Ok(Input::new()
+            .with_prompt("What is the RPC endpoint?")
+            .interact_text()?)
+    }
+
+    pub async fn process(self, output_format: crate::common::OutputFormat) -> crate::CliResult {
+        let connection_config = crate::common::ConnectionConfig::from_custom_url(&self.url);
+        display_network_status(connection_config, output_format).await
+    }
+}
+
+async fn display_network_status(
+    connection_config: crate::common::ConnectionConfig,
+    output_format: crate::common::OutputFormat,
+) -> crate::CliResult {
+    let status = near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::status::RpcStatusRequest)
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch the network status: {:?}", err))
+        })?;
+
+    if let crate::common::OutputFormat::Json = output_format {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("Chain ID:          {}", status.chain_id);
+    println!(
+        "Node version:      {} (build {})",
+        status.version.version, status.version.build
+    );
+    println!(
+        "Protocol version:  {} (the latest supported by the node: {})",
+        status.protocol_version, status.latest_protocol_version
+    );
+    println!(
+        "Latest block:      #{} ({}) at {}",
+        status.sync_info.latest_block_height,
+        status.sync_info.latest_block_hash,
+        status.sync_info.latest_block_time
+    );
+    println!(
+        "Sync state:        {}",
+        if status.sync_info.syncing {
+            "syncing"
+        } else {
+            "synced"
+        }
+    );
+    if let Some(validator_account_id) = &status.validator_account_id {
+        println!("Validator account: {}", validator_account_id);
+    }
+    println!("\nCurrent validators ({})", status.validators.len());
+    for validator in status.validators.iter() {
+        if validator.is_slashed {
+            println!("  {} (slashed)", validator.account_id);
+        } else {
+            println!("  {}", validator.account_id);
+        }
+    }
+    Ok(())
+}
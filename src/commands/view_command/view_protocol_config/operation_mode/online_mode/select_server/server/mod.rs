@@ -0,0 +1,234 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::SelectServerContext)]
+pub struct Server {}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::SelectServerContext)]
+#[interactive_clap(output_context = super::ViewProtocolConfigCommandNetworkContext)]
+pub struct CustomServer {
+    #[interactive_clap(long)]
+    pub url: crate::common::AvailableRpcServerUrl,
+}
+
+struct CustomServerContext {
+    pub url: crate::common::AvailableRpcServerUrl,
+}
+
+impl CustomServerContext {
+    fn _from_previous_context(
+        _previous_context: super::SelectServerContext,
+        scope: &<CustomServer as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            url: scope.url.clone(),
+        }
+    }
+}
+
+impl From<CustomServerContext> for super::ViewProtocolConfigCommandNetworkContext {
+    fn from(item: CustomServerContext) -> Self {
+        Self {
+            connection_config: crate::common::ConnectionConfig::from_custom_url(&item.url),
+        }
+    }
+}
+
+impl Server {
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        display_protocol_config(connection_config, output_format).await
+    }
+}
+
+impl CustomServer {
+    pub fn input_url(
+        _context: &super::SelectServerContext,
+    ) -> color_eyre::eyre::Result<crate::common::AvailableRpcServerUrl> {
+        Ok(Input::new()
+            .with_prompt("What is the RPC endpoint?")
+            .interact_text()?)
+    }
+
+    pub async fn process(self, output_format: crate::common::OutputFormat) -> crate::CliResult {
+        let connection_config = crate::common::ConnectionConfig::from_custom_url(&self.url);
+        display_protocol_config(connection_config, output_format).await
+    }
+}
+
+/// Numbers of the protocol config may be serialized as JSON numbers or, for u128, as strings.
+fn json_u128(value: &serde_json::Value) -> u128 {
+    match value {
+        serde_json::Value::String(value) => value.parse().unwrap_or_default(),
+        value => value.as_u64().unwrap_or_default() as u128,
+    }
+}
+
+fn display_gas(gas: u64) -> String {
+    if gas >= 10u64.pow(9) {
+        crate::common::NearGas::from(gas).to_string()
+    } else {
+        format!("{} Gas", gas)
+    }
+}
+
+fn display_ratio(value: &serde_json::Value) -> String {
+    format!("{}/{}", json_u128(&value[0]), json_u128(&value[1]))
+}
+
+fn display_fee(name: &str, fee: &serde_json::Value) {
+    println!(
+        "  {:<32} send (sir): {:<18} send (not sir): {:<18} execution: {}",
+        name,
+        display_gas(fee["send_sir"].as_u64().unwrap_or_default()),
+        display_gas(fee["send_not_sir"].as_u64().unwrap_or_default()),
+        display_gas(fee["execution"].as_u64().unwrap_or_default())
+    );
+}
+
+async fn display_protocol_config(
+    connection_config: crate::common::ConnectionConfig,
+    output_format: crate::common::OutputFormat,
+) -> crate::CliResult {
+    let protocol_config =
+        serde_json::to_value(crate::common::get_protocol_config(&connection_config).await?)?;
+
+    if let crate::common::OutputFormat::Json = output_format {
+        println!("{}", serde_json::to_string_pretty(&protocol_config)?);
+        return Ok(());
+    }
+
+    let runtime_config = &protocol_config["runtime_config"];
+    let limit_config = &runtime_config["wasm_config"]["limit_config"];
+    let transaction_costs = &runtime_config["transaction_costs"];
+    let action_creation_config = &transaction_costs["action_creation_config"];
+    // Gas prices are in yoctoNEAR per gas unit, they are shown per TeraGas.
+    let gas_price_per_tera_gas = |value: &serde_json::Value| {
+        crate::common::NearBalance::from_yoctonear(json_u128(value) * 10u128.pow(12))
+    };
+    let storage_amount_per_byte = json_u128(&runtime_config["storage_amount_per_byte"]);
+
+    println!();
+    println!(
+        "Protocol version {} of {}",
+        protocol_config["protocol_version"],
+        protocol_config["chain_id"].as_str().unwrap_or_default()
+    );
+    println!("\nGeneral");
+    println!(
+        "  Genesis:                      #{} at {}",
+        protocol_config["genesis_height"],
+        protocol_config["genesis_time"].as_str().unwrap_or_default()
+    );
+    println!(
+        "  Epoch length:                 {} blocks",
+        protocol_config["epoch_length"]
+    );
+    println!(
+        "  Block producer seats:         {}",
+        protocol_config["num_block_producer_seats"]
+    );
+    println!(
+        "  Minimum stake ratio:          {}",
+        display_ratio(&protocol_config["minimum_stake_ratio"])
+    );
+    println!(
+        "  Transaction validity period:  {} blocks",
+        protocol_config["transaction_validity_period"]
+    );
+    println!(
+        "  Gas price:                    from {} to {} per TeraGas",
+        gas_price_per_tera_gas(&protocol_config["min_gas_price"]),
+        gas_price_per_tera_gas(&protocol_config["max_gas_price"])
+    );
+    println!(
+        "  Gas limit per chunk:          {}",
+        display_gas(protocol_config["gas_limit"].as_u64().unwrap_or_default())
+    );
+    println!("\nStorage");
+    println!(
+        "  Storage price:                {} per byte ({} per 100 kB)",
+        crate::common::NearBalance::from_yoctonear(storage_amount_per_byte),
+        crate::common::NearBalance::from_yoctonear(storage_amount_per_byte * 100_000)
+    );
+    println!(
+        "  Min top-level account length: {} (registrar: {})",
+        runtime_config["account_creation_config"]["min_allowed_top_level_account_length"],
+        runtime_config["account_creation_config"]["registrar_account_id"]
+            .as_str()
+            .unwrap_or_default()
+    );
+    println!("\nLimits");
+    println!(
+        "  Max gas burnt per call:       {}",
+        display_gas(limit_config["max_gas_burnt"].as_u64().unwrap_or_default())
+    );
+    println!(
+        "  Max prepaid gas per tx:       {}",
+        display_gas(
+            limit_config["max_total_prepaid_gas"]
+                .as_u64()
+                .unwrap_or_default()
+        )
+    );
+    println!(
+        "  Max contract size:            {} bytes",
+        limit_config["max_contract_size"]
+    );
+    println!(
+        "  Max transaction size:         {} bytes",
+        limit_config["max_transaction_size"]
+    );
+    println!(
+        "  Max arguments length:         {} bytes",
+        limit_config["max_arguments_length"]
+    );
+    println!(
+        "  Max number of logs:           {} ({} bytes in total)",
+        limit_config["max_number_logs"], limit_config["max_total_log_length"]
+    );
+    println!("\nFees");
+    display_fee(
+        "Action receipt creation",
+        &transaction_costs["action_receipt_creation_config"],
+    );
+    display_fee(
+        "Data receipt creation",
+        &transaction_costs["data_receipt_creation_config"]["base_cost"],
+    );
+    display_fee(
+        "Data receipt creation per byte",
+        &transaction_costs["data_receipt_creation_config"]["cost_per_byte"],
+    );
+    for (name, fee) in [
+        ("Create account", "create_account_cost"),
+        ("Deploy contract", "deploy_contract_cost"),
+        ("Deploy contract per byte", "deploy_contract_cost_per_byte"),
+        ("Function call", "function_call_cost"),
+        ("Function call per byte", "function_call_cost_per_byte"),
+        ("Transfer", "transfer_cost"),
+        ("Stake", "stake_cost"),
+        ("Delete key", "delete_key_cost"),
+        ("Delete account", "delete_account_cost"),
+    ]
+    .iter()
+    {
+        display_fee(name, &action_creation_config[fee]);
+    }
+    let add_key_cost = &action_creation_config["add_key_cost"];
+    display_fee("Add full access key", &add_key_cost["full_access_cost"]);
+    display_fee("Add function call key", &add_key_cost["function_call_cost"]);
+    display_fee(
+        "Add function call key per byte",
+        &add_key_cost["function_call_cost_per_byte"],
+    );
+    println!(
+        "  Burnt gas reward:             {}",
+        display_ratio(&transaction_costs["burnt_gas_reward"])
+    );
+    Ok(())
+}
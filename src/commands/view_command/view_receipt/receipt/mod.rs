@@ -0,0 +1,78 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewReceiptCommandNetworkContext)]
+pub struct Receipt {
+    pub receipt_id: crate::types::crypto_hash::CryptoHash,
+}
+
+impl Receipt {
+    fn input_receipt_id(
+        _context: &super::operation_mode::online_mode::select_server::ViewReceiptCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::crypto_hash::CryptoHash> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter the ID of the receipt you need to view")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        let receipt_view =
+            crate::common::get_receipt(&connection_config, self.receipt_id.into()).await?;
+        if let crate::common::OutputFormat::Json = output_format {
+            println!("{}", serde_json::to_string_pretty(&receipt_view)?);
+            return Ok(());
+        }
+        println!();
+        println!("Receipt ID:   {}", receipt_view.receipt_id);
+        println!("Predecessor:  {}", receipt_view.predecessor_id);
+        println!("Receiver:     {}", receipt_view.receiver_id);
+        match &receipt_view.receipt {
+            near_primitives::views::ReceiptEnumView::Action {
+                signer_id,
+                signer_public_key,
+                gas_price,
+                output_data_receivers,
+                input_data_ids,
+                actions,
+            } => {
+                println!("Signer:       {} ({})", signer_id, signer_public_key);
+                println!(
+                    "Gas price:    {}",
+                    crate::common::NearBalance::from_yoctonear(*gas_price)
+                );
+                println!("Actions:");
+                for action in actions.iter() {
+                    println!("  {}", crate::common::action_view_summary(action));
+                }
+                if !input_data_ids.is_empty() {
+                    println!("Input data IDs:");
+                    for data_id in input_data_ids.iter() {
+                        println!("  {}", data_id);
+                    }
+                }
+                if !output_data_receivers.is_empty() {
+                    println!("Output data receivers:");
+                    for data_receiver in output_data_receivers.iter() {
+                        println!(
+                            "  {} -> {}",
+                            data_receiver.data_id, data_receiver.receiver_id
+                        );
+                    }
+                }
+            }
+            near_primitives::views::ReceiptEnumView::Data { data_id, data } => {
+                println!("Data ID:      {}", data_id);
+                match data {
+                    Some(data) => println!("Data:         {} bytes", data.len()),
+                    None => println!("Data:         none (the promise failed)"),
+                }
+            }
+        }
+        Ok(())
+    }
+}
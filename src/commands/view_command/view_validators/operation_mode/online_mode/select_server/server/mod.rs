@@ -0,0 +1,233 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::SelectServerContext)]
+pub struct Server {}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::SelectServerContext)]
+#[interactive_clap(output_context = super::ViewValidatorsCommandNetworkContext)]
+pub struct CustomServer {
+    #[interactive_clap(long)]
+    pub url: crate::common::AvailableRpcServerUrl,
+}
+
+struct CustomServerContext {
+    pub url: crate::common::AvailableRpcServerUrl,
+}
+
+impl CustomServerContext {
+    fn _from_previous_context(
+        _previous_context: super::SelectServerContext,
+        scope: &<CustomServer as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            url: scope.url.clone(),
+        }
+    }
+}
+
+impl From<CustomServerContext> for super::ViewValidatorsCommandNetworkContext {
+    fn from(item: CustomServerContext) -> Self {
+        Self {
+            connection_config: crate::common::ConnectionConfig::from_custom_url(&item.url),
+        }
+    }
+}
+
+impl Server {
+    pub async fn process(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        display_validators(connection_config, output_format).await
+    }
+}
+
+impl CustomServer {
+    pub fn input_url(
+        _context: &super::SelectServerContext,
+    ) -> color_eyre::eyre::Result<crate::common::AvailableRpcServerUrl> {
+        Ok(Input::new()
+            .with_prompt("What is the RPC endpoint?")
+            .interact_text()?)
+    }
+
+    pub async fn process(self, output_format: crate::common::OutputFormat) -> crate::CliResult {
+        let connection_config = crate::common::ConnectionConfig::from_custom_url(&self.url);
+        display_validators(connection_config, output_format).await
+    }
+}
+
+/// Estimates the seat price the same way as nearcore does since protocol version 49:
+/// if there are free seats, it is the minimum stake ratio of the total stake,
+/// otherwise it is the smallest stake plus one yoctoNEAR.
+fn estimate_seat_price(
+    mut stakes: Vec<near_primitives::types::Balance>,
+    num_seats: u64,
+    minimum_stake_ratio: (u128, u128),
+) -> near_primitives::types::Balance {
+    stakes.sort();
+    let stakes_sum: u128 = stakes.iter().sum();
+    if (stakes.len() as u64) < num_seats {
+        stakes_sum * minimum_stake_ratio.0 / minimum_stake_ratio.1
+    } else {
+        stakes.first().map(|stake| stake + 1).unwrap_or_default()
+    }
+}
+
+fn validator_stake_view_fields(
+    validator_stake_view: &near_primitives::views::validator_stake_view::ValidatorStakeView,
+) -> (String, near_primitives::types::Balance) {
+    let validator_stake = validator_stake_view.clone().into_validator_stake();
+    (
+        validator_stake.account_id().to_string(),
+        validator_stake.stake(),
+    )
+}
+
+async fn display_validators(
+    connection_config: crate::common::ConnectionConfig,
+    output_format: crate::common::OutputFormat,
+) -> crate::CliResult {
+    let epoch_validator_info =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(
+                near_jsonrpc_client::methods::validators::RpcValidatorRequest {
+                    epoch_reference: near_primitives::types::EpochReference::Latest,
+                },
+            )
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to fetch validators: {:?}", err))
+            })?;
+    let protocol_config =
+        serde_json::to_value(crate::common::get_protocol_config(&connection_config).await?)?;
+    let num_seats = protocol_config["num_block_producer_seats"]
+        .as_u64()
+        .unwrap_or_default();
+    let minimum_stake_ratio = (
+        protocol_config["minimum_stake_ratio"][0]
+            .as_u64()
+            .unwrap_or(1) as u128,
+        protocol_config["minimum_stake_ratio"][1]
+            .as_u64()
+            .unwrap_or(6250) as u128,
+    );
+    let current_seat_price = estimate_seat_price(
+        epoch_validator_info
+            .current_validators
+            .iter()
+            .map(|validator| validator.stake)
+            .collect(),
+        num_seats,
+        minimum_stake_ratio,
+    );
+    let next_seat_price = estimate_seat_price(
+        epoch_validator_info
+            .next_validators
+            .iter()
+            .map(|validator| validator.stake)
+            .collect(),
+        num_seats,
+        minimum_stake_ratio,
+    );
+
+    if let crate::common::OutputFormat::Json = output_format {
+        let mut json = serde_json::to_value(&epoch_validator_info)?;
+        json["current_seat_price"] = serde_json::Value::String(current_seat_price.to_string());
+        json["next_seat_price"] = serde_json::Value::String(next_seat_price.to_string());
+        println!("{}", serde_json::to_string_pretty(&json)?);
+        return Ok(());
+    }
+
+    println!(
+        "Epoch #{} (started at block #{})",
+        epoch_validator_info.epoch_height, epoch_validator_info.epoch_start_height
+    );
+
+    let mut current_validators = epoch_validator_info.current_validators.clone();
+    current_validators.sort_by(|a, b| b.stake.cmp(&a.stake));
+    println!(
+        "\nCurrent validators ({}), seat price: {}",
+        current_validators.len(),
+        crate::common::NearBalance::from_yoctonear(current_seat_price)
+    );
+    for validator in current_validators.iter() {
+        println!(
+            "  {}{}\n    Stake: {}\n    Blocks produced/expected: {}/{} ({}%)\n    Chunks produced/expected: {}/{} ({}%)",
+            validator.account_id,
+            if validator.is_slashed { " (slashed)" } else { "" },
+            crate::common::NearBalance::from_yoctonear(validator.stake),
+            validator.num_produced_blocks,
+            validator.num_expected_blocks,
+            percentage(validator.num_produced_blocks, validator.num_expected_blocks),
+            validator.num_produced_chunks,
+            validator.num_expected_chunks,
+            percentage(validator.num_produced_chunks, validator.num_expected_chunks),
+        );
+    }
+
+    let mut next_validators = epoch_validator_info.next_validators.clone();
+    next_validators.sort_by(|a, b| b.stake.cmp(&a.stake));
+    println!(
+        "\nNext validators ({}), seat price: {}",
+        next_validators.len(),
+        crate::common::NearBalance::from_yoctonear(next_seat_price)
+    );
+    for validator in next_validators.iter() {
+        let current_stake = epoch_validator_info
+            .current_validators
+            .iter()
+            .find(|current_validator| current_validator.account_id == validator.account_id)
+            .map(|current_validator| current_validator.stake);
+        println!(
+            "  {}: {}{}",
+            validator.account_id,
+            crate::common::NearBalance::from_yoctonear(validator.stake),
+            match current_stake {
+                None => " (new)".to_string(),
+                Some(current_stake) if current_stake > validator.stake => format!(
+                    " (-{})",
+                    crate::common::NearBalance::from_yoctonear(current_stake - validator.stake)
+                ),
+                Some(current_stake) if current_stake < validator.stake => format!(
+                    " (+{})",
+                    crate::common::NearBalance::from_yoctonear(validator.stake - current_stake)
+                ),
+                Some(_) => "".to_string(),
+            }
+        );
+    }
+
+    println!(
+        "\nCurrent proposals ({})",
+        epoch_validator_info.current_proposals.len()
+    );
+    for proposal in epoch_validator_info.current_proposals.iter() {
+        let (account_id, stake) = validator_stake_view_fields(proposal);
+        println!(
+            "  {}: {}",
+            account_id,
+            crate::common::NearBalance::from_yoctonear(stake)
+        );
+    }
+
+    println!(
+        "\nKicked out in the previous epoch ({})",
+        epoch_validator_info.prev_epoch_kickout.len()
+    );
+    for kickout in epoch_validator_info.prev_epoch_kickout.iter() {
+        println!("  {}: {:?}", kickout.account_id, kickout.reason);
+    }
+    Ok(())
+}
+
+fn percentage(produced: u64, expected: u64) -> u64 {
+    if expected == 0 {
+        100
+    } else {
+        produced * 100 / expected
+    }
+}
@@ -46,6 +46,8 @@ pub struct SignerContext {
 #[derive(
     Debug,
     Clone,
+    Copy,
+    PartialEq,
     strum_macros::IntoStaticStr,
     strum_macros::EnumString,
     strum_macros::EnumVariantNames,
@@ -67,6 +69,10 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+impl interactive_clap::ToCli for OutputFormat {
+    type CliVariant = OutputFormat;
+}
+
 #[derive(Debug, Clone)]
 pub struct SignedTransactionAsBase64 {
     pub inner: near_primitives::transaction::SignedTransaction,
@@ -478,6 +484,10 @@ impl std::fmt::Display for AccountTransferAllowance {
 }
 
 impl AccountTransferAllowance {
+    pub fn account_liquid_balance(&self) -> NearBalance {
+        self.account_liquid_balance.clone()
+    }
+
     pub fn liquid_storage_stake(&self) -> NearBalance {
         NearBalance::from_yoctonear(
             self.storage_stake
@@ -488,9 +498,10 @@ impl AccountTransferAllowance {
 
     pub fn transfer_allowance(&self) -> NearBalance {
         NearBalance::from_yoctonear(
-            self.account_liquid_balance.to_yoctonear()
-                - self.liquid_storage_stake().to_yoctonear()
-                - self.pessimistic_transaction_fee.to_yoctonear(),
+            self.account_liquid_balance
+                .to_yoctonear()
+                .saturating_sub(self.liquid_storage_stake().to_yoctonear())
+                .saturating_sub(self.pessimistic_transaction_fee.to_yoctonear()),
         )
     }
 }
@@ -512,18 +523,7 @@ pub fn get_account_transfer_allowance(
             });
         };
     let storage_amount_per_byte = actix::System::new()
-        .block_on(async {
-            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
-                .call(
-                    near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
-                        block_reference: near_primitives::types::BlockReference::Finality(
-                            near_primitives::types::Finality::Final,
-                        ),
-                    },
-                )
-                .await
-        })
-        .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))?
+        .block_on(get_protocol_config(connection_config))?
         .runtime_config
         .storage_amount_per_byte;
 
@@ -541,6 +541,256 @@ pub fn get_account_transfer_allowance(
     })
 }
 
+/// The same as `get_account_transfer_allowance`, but with the given fee of the transactions
+/// instead of the pessimistic estimate.
+pub async fn get_account_transfer_allowance_with_fee(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    transaction_fee: NearBalance,
+) -> color_eyre::eyre::Result<AccountTransferAllowance> {
+    let account_view = get_account_view(
+        connection_config,
+        account_id.clone(),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    let storage_amount_per_byte = get_protocol_config(connection_config)
+        .await?
+        .runtime_config
+        .storage_amount_per_byte;
+    Ok(AccountTransferAllowance {
+        account_id,
+        account_liquid_balance: NearBalance::from_yoctonear(account_view.amount),
+        account_locked_balance: NearBalance::from_yoctonear(account_view.locked),
+        storage_stake: NearBalance::from_yoctonear(
+            u128::from(account_view.storage_usage) * storage_amount_per_byte,
+        ),
+        pessimistic_transaction_fee: transaction_fee,
+    })
+}
+
+pub async fn get_protocol_config(
+    connection_config: &ConnectionConfig,
+) -> color_eyre::eyre::Result<
+    near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigResponse,
+> {
+    near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+        .call(
+            near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
+                block_reference: near_primitives::types::BlockReference::Finality(
+                    near_primitives::types::Finality::Final,
+                ),
+            },
+        )
+        .await
+        .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))
+}
+
+fn protocol_config_ratio(value: &serde_json::Value) -> (u128, u128) {
+    (
+        value[0].as_u64().unwrap_or(1) as u128,
+        value[1].as_u64().unwrap_or(1) as u128,
+    )
+}
+
+/// Calculates the cost of a transaction with a single Transfer action.
+pub async fn get_transfer_transaction_fee(
+    connection_config: &ConnectionConfig,
+    signer_id: &AccountId,
+    receiver_id: &AccountId,
+) -> color_eyre::eyre::Result<NearBalance> {
+    get_transaction_fee(
+        connection_config,
+        signer_id,
+        receiver_id,
+        &[near_primitives::transaction::Action::Transfer(
+            near_primitives::transaction::TransferAction { deposit: 0 },
+        )],
+    )
+    .await
+}
+
+/// Calculates the fee of a transaction (without the deposits) the same way as `tx_cost` of
+/// nearcore does: the send fees are burnt at the gas price and the execution fees together with
+/// the prepaid gas of function calls are prepaid at the pessimistic gas price (the difference is
+/// refunded later). The pessimistic gas price is inflated once for every receipt the prepaid gas
+/// can pay for, and the gas price of the next block is used, so the fee is never lower than the
+/// amount nearcore charges.
+pub async fn get_transaction_fee(
+    connection_config: &ConnectionConfig,
+    signer_id: &AccountId,
+    receiver_id: &AccountId,
+    actions: &[near_primitives::transaction::Action],
+) -> color_eyre::eyre::Result<NearBalance> {
+    let protocol_config = serde_json::to_value(get_protocol_config(connection_config).await?)?;
+    let transaction_costs = &protocol_config["runtime_config"]["transaction_costs"];
+    let action_creation_config = &transaction_costs["action_creation_config"];
+    let sender_is_receiver = signer_id == receiver_id;
+    // The fees with the number of times they are charged.
+    let mut fees: Vec<(&serde_json::Value, u128)> =
+        vec![(&transaction_costs["action_receipt_creation_config"], 1)];
+    let mut prepaid_gas: u128 = 0;
+    for action in actions {
+        match action {
+            near_primitives::transaction::Action::CreateAccount(_) => {
+                fees.push((&action_creation_config["create_account_cost"], 1));
+            }
+            near_primitives::transaction::Action::DeployContract(deploy_contract_action) => {
+                fees.push((&action_creation_config["deploy_contract_cost"], 1));
+                fees.push((
+                    &action_creation_config["deploy_contract_cost_per_byte"],
+                    deploy_contract_action.code.len() as u128,
+                ));
+            }
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                fees.push((&action_creation_config["function_call_cost"], 1));
+                fees.push((
+                    &action_creation_config["function_call_cost_per_byte"],
+                    (function_call_action.method_name.len() + function_call_action.args.len())
+                        as u128,
+                ));
+                prepaid_gas += function_call_action.gas as u128;
+            }
+            near_primitives::transaction::Action::Transfer(_) => {
+                fees.push((&action_creation_config["transfer_cost"], 1));
+                // A transfer to an implicit account is charged for the creation of the account
+                // with a full access key.
+                if is_64_len_hex(receiver_id) {
+                    fees.push((&action_creation_config["create_account_cost"], 1));
+                    fees.push((
+                        &action_creation_config["add_key_cost"]["full_access_cost"],
+                        1,
+                    ));
+                }
+            }
+            near_primitives::transaction::Action::Stake(_) => {
+                fees.push((&action_creation_config["stake_cost"], 1));
+            }
+            near_primitives::transaction::Action::AddKey(add_key_action) => {
+                match &add_key_action.access_key.permission {
+                    near_primitives::account::AccessKeyPermission::FullAccess => {
+                        fees.push((
+                            &action_creation_config["add_key_cost"]["full_access_cost"],
+                            1,
+                        ));
+                    }
+                    near_primitives::account::AccessKeyPermission::FunctionCall(
+                        function_call_permission,
+                    ) => {
+                        fees.push((
+                            &action_creation_config["add_key_cost"]["function_call_cost"],
+                            1,
+                        ));
+                        fees.push((
+                            &action_creation_config["add_key_cost"]["function_call_cost_per_byte"],
+                            function_call_permission
+                                .method_names
+                                .iter()
+                                .map(|method_name| method_name.len() as u128 + 1)
+                                .sum(),
+                        ));
+                    }
+                }
+            }
+            near_primitives::transaction::Action::DeleteKey(_) => {
+                fees.push((&action_creation_config["delete_key_cost"], 1));
+            }
+            near_primitives::transaction::Action::DeleteAccount(_) => {
+                fees.push((&action_creation_config["delete_account_cost"], 1));
+            }
+        }
+    }
+    let send_fee_name = if sender_is_receiver {
+        "send_sir"
+    } else {
+        "send_not_sir"
+    };
+    let send_gas: u128 = fees
+        .iter()
+        .map(|(fee, count)| fee[send_fee_name].as_u64().unwrap_or_default() as u128 * count)
+        .sum();
+    let execution_gas: u128 = fees
+        .iter()
+        .map(|(fee, count)| fee["execution"].as_u64().unwrap_or_default() as u128 * count)
+        .sum::<u128>()
+        + prepaid_gas;
+    // The gas price may grow by the adjustment rate before the transaction is included,
+    // so the highest possible gas price of the next block is used.
+    let (adjustment_numerator, adjustment_denominator) =
+        protocol_config_ratio(&protocol_config["gas_price_adjustment_rate"]);
+    let gas_price = get_block(
+        connection_config,
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?
+    .header
+    .gas_price;
+    let gas_price =
+        (gas_price * (adjustment_denominator + adjustment_numerator) + adjustment_denominator - 1)
+            / adjustment_denominator;
+    // The receipt to another account is executed in the next block, and every receipt created
+    // with the prepaid gas may be executed one more block later.
+    let initial_receipt_hop = if sender_is_receiver { 0 } else { 1 };
+    let min_send_and_exec_fee = |fee: &serde_json::Value| {
+        std::cmp::min(
+            fee["send_sir"].as_u64().unwrap_or_default(),
+            fee["send_not_sir"].as_u64().unwrap_or_default(),
+        ) as u128
+            + fee["execution"].as_u64().unwrap_or_default() as u128
+    };
+    let min_receipt_with_function_call_gas =
+        min_send_and_exec_fee(&transaction_costs["action_receipt_creation_config"])
+            + min_send_and_exec_fee(&action_creation_config["function_call_cost"]);
+    // nearcore keeps the inflation exponent in u8.
+    let maximum_depth = if min_receipt_with_function_call_gas > 0 {
+        std::cmp::min(
+            prepaid_gas / min_receipt_with_function_call_gas,
+            u8::MAX as u128 - 1,
+        )
+    } else {
+        0
+    };
+    let (inflation_numerator, inflation_denominator) =
+        protocol_config_ratio(&transaction_costs["pessimistic_gas_price_inflation_ratio"]);
+    let mut receipt_gas_price = gas_price;
+    for _ in 0..initial_receipt_hop + maximum_depth {
+        receipt_gas_price = (receipt_gas_price * inflation_numerator + inflation_denominator - 1)
+            / inflation_denominator;
+    }
+    Ok(NearBalance::from_yoctonear(
+        send_gas * gas_price + execution_gas * receipt_gas_price,
+    ))
+}
+
+/// The largest amount the signer can transfer to the receiver in a single transfer transaction:
+/// the liquid balance minus the storage stake and the transaction fee.
+pub async fn get_max_transfer_amount(
+    connection_config: &ConnectionConfig,
+    signer_id: &AccountId,
+    receiver_id: &AccountId,
+) -> color_eyre::eyre::Result<NearBalance> {
+    let account_view = get_account_view(
+        connection_config,
+        signer_id.clone(),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    let storage_amount_per_byte = get_protocol_config(connection_config)
+        .await?
+        .runtime_config
+        .storage_amount_per_byte;
+    let liquid_storage_stake = (u128::from(account_view.storage_usage) * storage_amount_per_byte)
+        .saturating_sub(account_view.locked);
+    let transaction_fee =
+        get_transfer_transaction_fee(connection_config, signer_id, receiver_id).await?;
+    Ok(NearBalance::from_yoctonear(
+        account_view
+            .amount
+            .saturating_sub(liquid_storage_stake)
+            .saturating_sub(transaction_fee.to_yoctonear()),
+    ))
+}
+
 pub fn get_account_state(
     connection_config: &ConnectionConfig,
     account_id: near_primitives::types::AccountId,
@@ -570,6 +820,327 @@ pub fn get_account_state(
     }
 }
 
+/// The same as `get_account_view` at the final block, but `None` if the account does not exist.
+pub async fn get_account_view_if_exists(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<near_primitives::views::AccountView>> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccount {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await;
+    match query_view_method_response {
+        Ok(rpc_query_response) => {
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(
+                account_view,
+            ) = rpc_query_response.kind
+            {
+                Ok(Some(account_view))
+            } else {
+                Err(color_eyre::Report::msg(format!("Error call result")))
+            }
+        }
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+            ),
+        )) => Ok(None),
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to fetch the account <{}>: {:?}",
+            account_id, err
+        ))),
+    }
+}
+
+/// Returns the names of the functions exported by the contract deployed on the account at the
+/// final block, or `None` if the account does not exist or has no contract.
+pub async fn get_contract_exported_functions(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<Vec<String>>> {
+    match get_account_view_if_exists(connection_config, account_id.clone()).await? {
+        Some(account_view) if account_view.code_hash != CryptoHash::default() => {}
+        _ => return Ok(None),
+    }
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: QueryRequest::ViewCode {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view contract: {:?}",
+                    err
+                ))
+            })?;
+    let contract_code_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    let summary = parse_wasm_module(&contract_code_view.code).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The contract code of <{}> is invalid: {}",
+            account_id, err
+        ))
+    })?;
+    Ok(Some(summary.exported_functions))
+}
+
+pub async fn get_account_view(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    block_reference: BlockReference,
+) -> color_eyre::eyre::Result<near_primitives::views::AccountView> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::ViewAccount {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch the account <{}>: {:?}",
+                    account_id, err
+                ))
+            })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(account_view) =
+        query_view_method_response.kind
+    {
+        Ok(account_view)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+/// The same as `get_account_view`, but an account which does not exist at the block is `None`.
+pub async fn get_account_view_at_block_if_exists(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    block_reference: BlockReference,
+) -> color_eyre::eyre::Result<Option<near_primitives::views::AccountView>> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::ViewAccount {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await;
+    match query_view_method_response {
+        Ok(rpc_query_response) => {
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(
+                account_view,
+            ) = rpc_query_response.kind
+            {
+                Ok(Some(account_view))
+            } else {
+                Err(color_eyre::Report::msg(format!("Error call result")))
+            }
+        }
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+            ),
+        )) => Ok(None),
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to fetch the account <{}>: {:?}",
+            account_id, err
+        ))),
+    }
+}
+
+/// Fetches the access key from the archival RPC server; a key (or an account) which does not exist
+/// at the block is `None`, all the other errors are returned.
+pub async fn get_access_key_view_at_block_if_exists(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    block_reference: BlockReference,
+) -> color_eyre::eyre::Result<Option<near_primitives::views::AccessKeyView>> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: account_id.clone(),
+                    public_key: public_key.clone(),
+                },
+            })
+            .await;
+    match query_view_method_response {
+        Ok(rpc_query_response) => {
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+                access_key_view,
+            ) = rpc_query_response.kind
+            {
+                Ok(Some(access_key_view))
+            } else {
+                Err(color_eyre::Report::msg(format!("Error call result")))
+            }
+        }
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey { .. }
+                | near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+            ),
+        )) => Ok(None),
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to fetch the access key {} of <{}>: {:?}",
+            public_key, account_id, err
+        ))),
+    }
+}
+
+/// Calls a view method of the contract with JSON arguments and parses the JSON result.
+pub async fn call_view_function(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    method_name: &str,
+    args: serde_json::Value,
+    block_reference: BlockReference,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::CallFunction {
+                    account_id: contract_account_id.clone(),
+                    method_name: method_name.to_string(),
+                    args: near_primitives::types::FunctionArgs::from(args.to_string().into_bytes()),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to call the view method <{}> of the contract <{}>: {:?}",
+                    method_name, contract_account_id, err
+                ))
+            })?;
+    let call_result =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
+            query_view_method_response.kind
+        {
+            result.result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    serde_json::from_slice(&call_result)
+        .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))
+}
+
+/// Fungible token metadata (NEP-148).
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FtMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl FtMetadata {
+    pub fn balance(&self, amount: u128) -> crate::types::ft_amount::FtBalance {
+        crate::types::ft_amount::FtBalance {
+            amount,
+            decimals: self.decimals,
+            symbol: self.symbol.clone(),
+        }
+    }
+}
+
+pub async fn get_ft_metadata(
+    connection_config: &ConnectionConfig,
+    ft_contract_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<FtMetadata> {
+    let metadata = call_view_function(
+        connection_config,
+        ft_contract_account_id.clone(),
+        "ft_metadata",
+        serde_json::json!({}),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    serde_json::from_value(metadata).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The contract <{}> returned invalid fungible token metadata: {:?}",
+            ft_contract_account_id, err
+        ))
+    })
+}
+
+/// Parses a `U128` JSON value (a decimal string) returned by NEP-141/NEP-145 contracts.
+fn parse_u128_json_string(value: &serde_json::Value) -> color_eyre::eyre::Result<u128> {
+    value
+        .as_str()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| color_eyre::Report::msg(format!("Invalid U128 value: {}", value)))
+}
+
+pub async fn get_ft_balance(
+    connection_config: &ConnectionConfig,
+    ft_contract_account_id: near_primitives::types::AccountId,
+    account_id: near_primitives::types::AccountId,
+    block_reference: BlockReference,
+) -> color_eyre::eyre::Result<u128> {
+    parse_u128_json_string(
+        &call_view_function(
+            connection_config,
+            ft_contract_account_id,
+            "ft_balance_of",
+            serde_json::json!({ "account_id": account_id }),
+            block_reference,
+        )
+        .await?,
+    )
+}
+
+/// Returns the deposit required to register the account with the contract (NEP-145),
+/// or `None` if the account is already registered.
+pub async fn get_required_storage_deposit(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<NearBalance>> {
+    let storage_balance = call_view_function(
+        connection_config,
+        contract_account_id.clone(),
+        "storage_balance_of",
+        serde_json::json!({ "account_id": account_id }),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    if !storage_balance.is_null() {
+        return Ok(None);
+    }
+    let storage_balance_bounds = call_view_function(
+        connection_config,
+        contract_account_id,
+        "storage_balance_bounds",
+        serde_json::json!({}),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    Ok(Some(NearBalance::from_yoctonear(parse_u128_json_string(
+        &storage_balance_bounds["min"],
+    )?)))
+}
+
 /// Returns true if the account ID length is 64 characters and it's a hex representation. This is used to check the implicit account.
 pub fn is_64_len_hex(account_id: impl AsRef<str>) -> bool {
     let account_id = account_id.as_ref();
@@ -1139,11 +1710,13 @@ pub fn print_transaction_status(
     );
 }
 
-pub async fn save_access_key_to_keychain(
+/// Saves the key pair only in the `<account_id>` directory of the keychain, without the legacy
+/// `<account_id>.json` file, and returns the path of the saved file with the saved data.
+pub fn save_access_key_to_account_dir(
     network_connection_config: Option<crate::common::ConnectionConfig>,
     key_pair_properties: crate::common::KeyPairProperties,
     account_id: &str,
-) -> crate::CliResult {
+) -> color_eyre::eyre::Result<(std::path::PathBuf, String)> {
     let buf = format!(
         "{}",
         serde_json::json!({
@@ -1171,17 +1744,37 @@ pub async fn save_access_key_to_keychain(
     path_with_key_name.push(file_with_key_name);
     std::fs::File::create(&path_with_key_name)
         .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-        .write(buf.as_bytes())
+        .write_all(buf.as_bytes())
         .map_err(|err| color_eyre::Report::msg(format!("Failed to write to file: {:?}", err)))?;
     println!(
         "The data for the access key is saved in a file {}",
         &path_with_key_name.display()
     );
+    Ok((path_with_key_name, buf))
+}
 
+/// Saves the key pair in the `<account_id>` directory of the keychain and in the legacy
+/// `<account_id>.json` file if it is missing, and returns the paths of the created files.
+pub async fn save_access_key_to_keychain(
+    network_connection_config: Option<crate::common::ConnectionConfig>,
+    key_pair_properties: crate::common::KeyPairProperties,
+    account_id: &str,
+) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let (path_with_key_name, buf) = save_access_key_to_account_dir(
+        network_connection_config.clone(),
+        key_pair_properties,
+        account_id,
+    )?;
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    let dir_name = match &network_connection_config {
+        Some(connection_config) => connection_config.dir_name(),
+        None => crate::consts::DIR_NAME_KEY_CHAIN,
+    };
     let file_with_account_name: std::path::PathBuf = format!("{}.json", account_id).into();
     let mut path_with_account_name = std::path::PathBuf::from(&home_dir);
     path_with_account_name.push(dir_name);
     path_with_account_name.push(file_with_account_name);
+    let mut file_paths = vec![path_with_key_name];
     if path_with_account_name.exists() {
         println!(
             "The file: {} already exists! Therefore it was not overwritten.",
@@ -1190,7 +1783,7 @@ pub async fn save_access_key_to_keychain(
     } else {
         std::fs::File::create(&path_with_account_name)
             .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-            .write(buf.as_bytes())
+            .write_all(buf.as_bytes())
             .map_err(|err| {
                 color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
             })?;
@@ -1198,8 +1791,21 @@ pub async fn save_access_key_to_keychain(
             "The data for the access key is saved in a file {}",
             &path_with_account_name.display()
         );
+        file_paths.push(path_with_account_name);
     };
-    Ok(())
+    Ok(file_paths)
+}
+
+/// Removes the key files saved for a new account that has not been created.
+pub fn remove_new_account_key_files(file_paths: &[std::path::PathBuf]) {
+    for file_path in file_paths {
+        if std::fs::remove_file(file_path).is_ok() {
+            println!(
+                "The account was not created, the key file {} is removed",
+                file_path.display()
+            );
+        }
+    }
 }
 
 pub fn try_external_subcommand_execution() -> CliResult {
@@ -1261,6 +1867,215 @@ fn path_directories() -> Vec<std::path::PathBuf> {
     dirs
 }
 
+pub async fn get_block(
+    connection_config: &ConnectionConfig,
+    block_reference: BlockReference,
+) -> color_eyre::eyre::Result<near_primitives::views::BlockView> {
+    near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::block::RpcBlockRequest { block_reference })
+        .await
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to fetch the block: {:?}", err)))
+}
+
+async fn get_block_if_exists(
+    connection_config: &ConnectionConfig,
+    block_height: near_primitives::types::BlockHeight,
+) -> color_eyre::eyre::Result<Option<near_primitives::views::BlockView>> {
+    match near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::block::RpcBlockRequest {
+            block_reference: BlockReference::BlockId(near_primitives::types::BlockId::Height(
+                block_height,
+            )),
+        })
+        .await
+    {
+        Ok(block_view) => Ok(Some(block_view)),
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::blocks::RpcBlockError::UnknownBlock { .. },
+            ),
+        )) => Ok(None),
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to fetch the block #{}: {:?}",
+            block_height, err
+        ))),
+    }
+}
+
+/// Finds the last block produced at or before the given time with a binary search by the block
+/// heights. `after_block` is a block produced before that time to narrow the search (the genesis
+/// block is used otherwise).
+pub async fn get_block_at_timestamp(
+    connection_config: &ConnectionConfig,
+    timestamp_nanosec: u64,
+    after_block: Option<near_primitives::views::BlockView>,
+) -> color_eyre::eyre::Result<near_primitives::views::BlockView> {
+    let final_block = get_block(
+        connection_config,
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    if final_block.header.timestamp_nanosec <= timestamp_nanosec {
+        return Ok(final_block);
+    }
+    let mut lower_block = match after_block {
+        Some(after_block) => after_block,
+        None => {
+            let genesis_height =
+                serde_json::to_value(get_protocol_config(connection_config).await?)?
+                    ["genesis_height"]
+                    .as_u64()
+                    .unwrap_or_default();
+            get_block(
+                connection_config,
+                BlockReference::BlockId(near_primitives::types::BlockId::Height(genesis_height)),
+            )
+            .await?
+        }
+    };
+    if lower_block.header.timestamp_nanosec > timestamp_nanosec {
+        return Err(color_eyre::Report::msg(format!(
+            "There are no blocks before {} (the first block #{} was produced at {})",
+            crate::types::utc_date_time::UtcDateTime::from_timestamp_nanosec(timestamp_nanosec),
+            lower_block.header.height,
+            crate::types::utc_date_time::UtcDateTime::from_timestamp_nanosec(
+                lower_block.header.timestamp_nanosec
+            )
+        )));
+    }
+    let mut upper_height = final_block.header.height;
+    while upper_height - lower_block.header.height > 1 {
+        let middle_height =
+            lower_block.header.height + (upper_height - lower_block.header.height) / 2;
+        // Not every height has a block, so the first block at or after the middle is taken.
+        let mut middle_block = None;
+        for block_height in middle_height..upper_height {
+            if let Some(block_view) = get_block_if_exists(connection_config, block_height).await? {
+                middle_block = Some(block_view);
+                break;
+            }
+        }
+        match middle_block {
+            Some(middle_block) if middle_block.header.timestamp_nanosec <= timestamp_nanosec => {
+                lower_block = middle_block
+            }
+            _ => upper_height = middle_height,
+        }
+    }
+    Ok(lower_block)
+}
+
+pub async fn get_chunk(
+    connection_config: &ConnectionConfig,
+    chunk_hash: CryptoHash,
+) -> color_eyre::eyre::Result<near_primitives::views::ChunkView> {
+    near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::chunk::RpcChunkRequest {
+            chunk_reference: near_jsonrpc_primitives::types::chunks::ChunkReference::ChunkHash {
+                chunk_id: chunk_hash,
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch the chunk <{}>: {:?}",
+                chunk_hash, err
+            ))
+        })
+}
+
+pub async fn get_receipt(
+    connection_config: &ConnectionConfig,
+    receipt_id: CryptoHash,
+) -> color_eyre::eyre::Result<near_primitives::views::ReceiptView> {
+    near_jsonrpc_client::JsonRpcClient::connect(connection_config.archival_rpc_url().as_str())
+        .call(
+            near_jsonrpc_client::methods::EXPERIMENTAL_receipt::RpcReceiptRequest {
+                receipt_reference: near_jsonrpc_primitives::types::receipts::ReceiptReference {
+                    receipt_id,
+                },
+            },
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch the receipt <{}>: {:?}",
+                receipt_id, err
+            ))
+        })
+}
+
+/// A short description of an action, e.g. `FunctionCall(ft_transfer)` or `Transfer(1 NEAR)`.
+pub fn action_view_summary(action: &near_primitives::views::ActionView) -> String {
+    match action {
+        near_primitives::views::ActionView::CreateAccount => "CreateAccount".to_string(),
+        near_primitives::views::ActionView::DeployContract { .. } => "DeployContract".to_string(),
+        near_primitives::views::ActionView::FunctionCall { method_name, .. } => {
+            format!("FunctionCall({})", method_name)
+        }
+        near_primitives::views::ActionView::Transfer { deposit } => {
+            format!("Transfer({})", NearBalance::from_yoctonear(*deposit))
+        }
+        near_primitives::views::ActionView::Stake { stake, .. } => {
+            format!("Stake({})", NearBalance::from_yoctonear(*stake))
+        }
+        near_primitives::views::ActionView::AddKey { public_key, .. } => {
+            format!("AddKey({})", public_key)
+        }
+        near_primitives::views::ActionView::DeleteKey { public_key } => {
+            format!("DeleteKey({})", public_key)
+        }
+        near_primitives::views::ActionView::DeleteAccount { beneficiary_id } => {
+            format!("DeleteAccount({})", beneficiary_id)
+        }
+    }
+}
+
+pub fn receipt_view_summary(receipt_view: &near_primitives::views::ReceiptView) -> String {
+    let kind = match &receipt_view.receipt {
+        near_primitives::views::ReceiptEnumView::Action { actions, .. } => actions
+            .iter()
+            .map(action_view_summary)
+            .collect::<Vec<_>>()
+            .join(", "),
+        near_primitives::views::ReceiptEnumView::Data { data_id, .. } => {
+            format!("Data({})", data_id)
+        }
+    };
+    format!(
+        "{}  {} -> {}  {}",
+        receipt_view.receipt_id, receipt_view.predecessor_id, receipt_view.receiver_id, kind
+    )
+}
+
+pub fn print_chunk_contents(chunk_view: &near_primitives::views::ChunkView) {
+    println!(
+        "Shard {} chunk {} (produced by {}): {} transaction(s), {} receipt(s)",
+        chunk_view.header.shard_id,
+        chunk_view.header.chunk_hash,
+        chunk_view.author,
+        chunk_view.transactions.len(),
+        chunk_view.receipts.len()
+    );
+    for transaction in chunk_view.transactions.iter() {
+        println!(
+            "  tx       {}  {} -> {}  {}",
+            transaction.hash,
+            transaction.signer_id,
+            transaction.receiver_id,
+            transaction
+                .actions
+                .iter()
+                .map(action_view_summary)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    for receipt_view in chunk_view.receipts.iter() {
+        println!("  receipt  {}", receipt_view_summary(receipt_view));
+    }
+}
+
 pub async fn display_account_info(
     account_id: AccountId,
     conf: &ConnectionConfig,
@@ -1364,6 +2179,507 @@ pub async fn display_access_key_list(
     Ok(())
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ContractStateViewOptions {
+    pub prefix: crate::types::state_key_prefix::StateKeyPrefix,
+    pub key_format: crate::types::state_data_format::StateDataFormat,
+    pub value_format: crate::types::state_data_format::StateDataFormat,
+    pub hide_proof: bool,
+    pub offset: usize,
+    pub limit: Option<usize>,
+    pub export_to: Option<std::path::PathBuf>,
+}
+
+/// What to do with the contract code fetched by `view contract-code`.
+#[derive(Debug, Clone)]
+pub enum ContractCodeViewMode {
+    /// Save the code to the file
+    Download(std::path::PathBuf),
+    /// Only show the hash of the code
+    Hash,
+    /// Compare the code with the local wasm file
+    Verify(std::path::PathBuf),
+}
+
+/// Fetches the contract code and checks its SHA-256 checksum against the account `code_hash`
+/// at the same block before saving, displaying or comparing it.
+pub async fn display_contract_code(
+    account_id: AccountId,
+    conf: &ConnectionConfig,
+    block_ref: BlockReference,
+    mode: ContractCodeViewMode,
+) -> crate::CliResult {
+    let resp = near_jsonrpc_client::JsonRpcClient::connect(&conf.archival_rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_ref,
+            request: QueryRequest::ViewCode {
+                account_id: account_id.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view contract: {:?}",
+                err
+            ))
+        })?;
+    let contract_code_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+            resp.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    let account_view = get_account_view(
+        conf,
+        account_id.clone(),
+        BlockReference::BlockId(near_primitives::types::BlockId::Hash(resp.block_hash)),
+    )
+    .await?;
+    let code_hash = near_primitives::hash::hash(&contract_code_view.code);
+    if code_hash != account_view.code_hash {
+        return Err(color_eyre::Report::msg(format!(
+            "The fetched contract code SHA-256 checksum ({}) doesn't match the code_hash of the account <{}> ({}) at block #{} ({}).",
+            hex::encode(code_hash.as_ref()),
+            account_id,
+            hex::encode(account_view.code_hash.as_ref()),
+            resp.block_height,
+            resp.block_hash
+        )));
+    }
+    println!(
+        "Contract code for '{}' at block #{} ({})\n\
+        Size: {} bytes\n\
+        SHA-256 checksum (hex): {} (matches the account code_hash)",
+        account_id,
+        resp.block_height,
+        resp.block_hash,
+        contract_code_view.code.len(),
+        hex::encode(code_hash.as_ref())
+    );
+    match mode {
+        ContractCodeViewMode::Download(file_path) => {
+            if let Some(dir_name) = file_path.parent() {
+                std::fs::create_dir_all(&dir_name)?;
+            }
+            std::fs::File::create(&file_path)
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to create file: {:?}", err))
+                })?
+                .write_all(&contract_code_view.code)
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
+                })?;
+            println!("\nThe file {:?} was downloaded successfully", file_path);
+        }
+        ContractCodeViewMode::Hash => {
+            println!("\nHash of the contract: {}", &contract_code_view.hash)
+        }
+        ContractCodeViewMode::Verify(file_path) => {
+            let local_code = std::fs::read(&file_path).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to open or read the file: {:?}.\nError: {:?}",
+                    &file_path, err
+                ))
+            })?;
+            let local_code_hash = near_primitives::hash::hash(&local_code);
+            println!(
+                "\nLocal file {:?}\n\
+                Size: {} bytes\n\
+                SHA-256 checksum (hex): {}",
+                file_path,
+                local_code.len(),
+                hex::encode(local_code_hash.as_ref())
+            );
+            if local_code_hash != code_hash {
+                return Err(color_eyre::Report::msg(format!(
+                    "The local file {:?} doesn't match the contract code deployed to <{}>.",
+                    file_path, account_id
+                )));
+            }
+            println!(
+                "\nThe local file matches the contract code deployed to <{}>.",
+                account_id
+            );
+        }
+    }
+    Ok(())
+}
+
+pub async fn get_contract_state(
+    account_id: AccountId,
+    conf: &ConnectionConfig,
+    block_ref: BlockReference,
+    prefix: crate::types::state_key_prefix::StateKeyPrefix,
+) -> color_eyre::eyre::Result<(
+    near_primitives::views::ViewStateResult,
+    near_primitives::types::BlockHeight,
+    CryptoHash,
+)> {
+    let resp = near_jsonrpc_client::JsonRpcClient::connect(&conf.archival_rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_ref,
+            request: QueryRequest::ViewState {
+                account_id,
+                prefix: prefix.into(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view contract state: {:?}",
+                err
+            ))
+        })?;
+    match resp.kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) => {
+            Ok((result, resp.block_height, resp.block_hash))
+        }
+        _ => Err(color_eyre::Report::msg("Error call result")),
+    }
+}
+
+pub async fn display_contract_state(
+    account_id: AccountId,
+    conf: &ConnectionConfig,
+    block_ref: BlockReference,
+    options: &ContractStateViewOptions,
+) -> crate::CliResult {
+    let (view_state, block_height, block_hash) =
+        get_contract_state(account_id.clone(), conf, block_ref, options.prefix.clone()).await?;
+
+    if let Some(file_path) = &options.export_to {
+        let snapshot = ContractStateSnapshot::from_view_state(
+            account_id.clone(),
+            block_height,
+            block_hash,
+            &options.prefix,
+            &view_state,
+        );
+        snapshot.save(file_path)?;
+        println!(
+            "The snapshot of {} state records for '{}' at block #{} is saved in a file {}\n",
+            snapshot.values.len(),
+            account_id,
+            block_height,
+            file_path.display()
+        );
+    }
+
+    let total = view_state.values.len();
+    let first = options.offset.min(total);
+    let last = match options.limit {
+        Some(limit) => first.saturating_add(limit).min(total),
+        None => total,
+    };
+    println!(
+        "Contract state for '{}' at block #{} ({})\n\
+        Key prefix: {}\n\
+        Showing records {}-{} of {}",
+        account_id,
+        block_height,
+        block_hash,
+        if options.prefix.0.is_empty() {
+            "<none>".to_string()
+        } else {
+            options.prefix.to_string()
+        },
+        if first < last { first + 1 } else { first },
+        last,
+        total
+    );
+    for (index, item) in view_state.values[first..last].iter().enumerate() {
+        let key = base64::decode(&item.key)
+            .map_err(|err| color_eyre::Report::msg(format!("Invalid state key: {}", err)))?;
+        let value = base64::decode(&item.value)
+            .map_err(|err| color_eyre::Report::msg(format!("Invalid state value: {}", err)))?;
+        // The prefix is shown once above, so only the meaningful part of the key is decoded
+        let key_suffix = key
+            .strip_prefix(options.prefix.0.as_slice())
+            .unwrap_or(&key);
+        println!(
+            "{: >6}. {}\n        {}",
+            first + index + 1,
+            options.key_format.to_display_string(key_suffix),
+            options.value_format.to_display_string(&value)
+        );
+    }
+    if last < total {
+        println!(
+            "\n{} more records are available (use --offset {} to view them)",
+            total - last,
+            last
+        );
+    }
+    if !options.hide_proof {
+        println!("\nContract state (proof):\n{:#?}\n", &view_state.proof);
+    }
+    Ok(())
+}
+
+/// Contract state records (base64-encoded as returned by the RPC) of an account at a block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContractStateSnapshot {
+    pub account_id: AccountId,
+    pub block_height: near_primitives::types::BlockHeight,
+    pub block_hash: CryptoHash,
+    pub prefix: String,
+    pub values: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractStateChange {
+    Added {
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Removed {
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Changed {
+        key: Vec<u8>,
+        old_value: Vec<u8>,
+        new_value: Vec<u8>,
+    },
+}
+
+impl ContractStateSnapshot {
+    pub fn from_view_state(
+        account_id: AccountId,
+        block_height: near_primitives::types::BlockHeight,
+        block_hash: CryptoHash,
+        prefix: &crate::types::state_key_prefix::StateKeyPrefix,
+        view_state: &near_primitives::views::ViewStateResult,
+    ) -> Self {
+        Self {
+            account_id,
+            block_height,
+            block_hash,
+            prefix: base64::encode(&prefix.0),
+            values: view_state
+                .values
+                .iter()
+                .map(|item| (item.key.clone(), item.value.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn save(&self, file_path: &std::path::Path) -> crate::CliResult {
+        if let Some(dir_name) = file_path.parent() {
+            std::fs::create_dir_all(dir_name)?;
+        }
+        std::fs::File::create(file_path)
+            .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
+            .write_all(serde_json::to_string_pretty(self)?.as_bytes())
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
+            })?;
+        Ok(())
+    }
+
+    pub fn load(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(file_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to open or read the file: {:?}.\nError: {:?}",
+                file_path, err
+            ))
+        })?;
+        serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "The file {:?} is not a contract state snapshot: {}",
+                file_path, err
+            ))
+        })
+    }
+
+    /// Lists the records that have to change to get the `new` state from this one, ordered by key.
+    pub fn diff(&self, new: &Self) -> color_eyre::eyre::Result<Vec<ContractStateChange>> {
+        let decode = |data: &String| {
+            base64::decode(data).map_err(|err| {
+                color_eyre::Report::msg(format!("Invalid base64 in the snapshot: {}", err))
+            })
+        };
+        let mut changes = vec![];
+        for (key, old_value) in self.values.iter() {
+            match new.values.get(key) {
+                Some(new_value) if new_value == old_value => {}
+                Some(new_value) => changes.push(ContractStateChange::Changed {
+                    key: decode(key)?,
+                    old_value: decode(old_value)?,
+                    new_value: decode(new_value)?,
+                }),
+                None => changes.push(ContractStateChange::Removed {
+                    key: decode(key)?,
+                    value: decode(old_value)?,
+                }),
+            }
+        }
+        for (key, new_value) in new.values.iter() {
+            if !self.values.contains_key(key) {
+                changes.push(ContractStateChange::Added {
+                    key: decode(key)?,
+                    value: decode(new_value)?,
+                });
+            }
+        }
+        changes.sort_by(|a, b| a.key().cmp(b.key()));
+        Ok(changes)
+    }
+}
+
+impl ContractStateChange {
+    pub fn key(&self) -> &[u8] {
+        match self {
+            Self::Added { key, .. } | Self::Removed { key, .. } | Self::Changed { key, .. } => key,
+        }
+    }
+}
+
+/// The parts of a WebAssembly module that matter before deploying it as a contract.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WasmModuleSummary {
+    pub exported_functions: Vec<String>,
+    pub has_debug_info: bool,
+}
+
+fn read_wasm_leb128_u32(reader: &mut &[u8]) -> Result<u32, String> {
+    let mut result: u32 = 0;
+    for shift in (0..35).step_by(7) {
+        let (byte, rest) = reader
+            .split_first()
+            .ok_or_else(|| "unexpected end of the module".to_string())?;
+        *reader = rest;
+        result |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err("invalid LEB128 number".to_string())
+}
+
+fn read_wasm_name(reader: &mut &[u8]) -> Result<String, String> {
+    let len = read_wasm_leb128_u32(reader)? as usize;
+    if reader.len() < len {
+        return Err("unexpected end of the module".to_string());
+    }
+    let (name, rest) = reader.split_at(len);
+    *reader = rest;
+    String::from_utf8(name.to_vec()).map_err(|_| "invalid UTF-8 name".to_string())
+}
+
+/// Checks the WebAssembly magic and version, and collects the exported functions.
+pub fn parse_wasm_module(code: &[u8]) -> Result<WasmModuleSummary, String> {
+    if code.len() < 8 || &code[..4] != b"\0asm" {
+        return Err(
+            "the file is not a WebAssembly module (the \\0asm magic bytes are missing)".to_string(),
+        );
+    }
+    if code[4..8] != [1, 0, 0, 0] {
+        return Err(format!(
+            "unsupported WebAssembly version {} (only version 1 is supported)",
+            u32::from_le_bytes([code[4], code[5], code[6], code[7]])
+        ));
+    }
+    let mut summary = WasmModuleSummary::default();
+    let mut reader = &code[8..];
+    while !reader.is_empty() {
+        let section_id = reader[0];
+        reader = &reader[1..];
+        let section_len = read_wasm_leb128_u32(&mut reader)? as usize;
+        if reader.len() < section_len {
+            return Err("the module is truncated".to_string());
+        }
+        let (mut section, rest) = reader.split_at(section_len);
+        reader = rest;
+        match section_id {
+            // custom section
+            0 => {
+                if read_wasm_name(&mut section)?.starts_with(".debug") {
+                    summary.has_debug_info = true;
+                }
+            }
+            // export section
+            7 => {
+                let count = read_wasm_leb128_u32(&mut section)?;
+                for _ in 0..count {
+                    let name = read_wasm_name(&mut section)?;
+                    let (kind, rest) = section
+                        .split_first()
+                        .ok_or_else(|| "unexpected end of the module".to_string())?;
+                    section = rest;
+                    read_wasm_leb128_u32(&mut section)?;
+                    if *kind == 0 {
+                        summary.exported_functions.push(name);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(summary)
+}
+
+/// Validates the contract code before it is deployed: the WebAssembly header, the protocol
+/// `max_contract_size` limit (when online) and the presence of the init method (if any).
+pub async fn check_contract_code(
+    code: &[u8],
+    network_connection_config: Option<&ConnectionConfig>,
+    init_method_name: Option<&str>,
+) -> color_eyre::eyre::Result<WasmModuleSummary> {
+    let summary = parse_wasm_module(code).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The contract code is invalid: {}. Make sure you are deploying the compiled .wasm file.",
+            err
+        ))
+    })?;
+    match network_connection_config {
+        Some(connection_config) => {
+            let max_contract_size = get_protocol_config(connection_config)
+                .await?
+                .runtime_config
+                .wasm_config
+                .limit_config
+                .max_contract_size;
+            println!(
+                "\nContract code size: {} bytes (the protocol limit is {} bytes)",
+                code.len(),
+                max_contract_size
+            );
+            if code.len() as u64 > max_contract_size {
+                return Err(color_eyre::Report::msg(format!(
+                    "The contract code size ({} bytes) exceeds the protocol limit ({} bytes).",
+                    code.len(),
+                    max_contract_size
+                )));
+            }
+        }
+        None => println!("\nContract code size: {} bytes", code.len()),
+    }
+    if summary.has_debug_info {
+        println!("WARNING! The contract code contains debug info. Did you forget to build it with --release?");
+    }
+    println!(
+        "Exported functions ({}): {}",
+        summary.exported_functions.len(),
+        summary.exported_functions.join(", ")
+    );
+    if let Some(init_method_name) = init_method_name {
+        if !summary
+            .exported_functions
+            .iter()
+            .any(|name| name == init_method_name)
+        {
+            println!(
+                "WARNING! The init method <{}> is not exported by the contract code, so the initialization call will fail.",
+                init_method_name
+            );
+        }
+    }
+    Ok(summary)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1719,4 +3035,65 @@ mod tests {
             Err("Near Gas: invalid digit found in string".to_string())
         );
     }
+    #[test]
+    fn contract_state_snapshot_diff() {
+        let snapshot = |values: &[(&str, &str)]| ContractStateSnapshot {
+            account_id: "contract.testnet".parse().unwrap(),
+            block_height: 0,
+            block_hash: CryptoHash::default(),
+            prefix: String::new(),
+            values: values
+                .iter()
+                .map(|(key, value)| (base64::encode(key), base64::encode(value)))
+                .collect(),
+        };
+        let old = snapshot(&[("a", "1"), ("b", "2"), ("c", "3")]);
+        let new = snapshot(&[("a", "1"), ("b", "20"), ("d", "4")]);
+        assert_eq!(
+            old.diff(&new).unwrap(),
+            vec![
+                ContractStateChange::Changed {
+                    key: b"b".to_vec(),
+                    old_value: b"2".to_vec(),
+                    new_value: b"20".to_vec()
+                },
+                ContractStateChange::Removed {
+                    key: b"c".to_vec(),
+                    value: b"3".to_vec()
+                },
+                ContractStateChange::Added {
+                    key: b"d".to_vec(),
+                    value: b"4".to_vec()
+                },
+            ]
+        );
+    }
+    #[test]
+    fn parse_wasm_module_exported_functions() {
+        let code = [
+            &b"\0asm"[..],
+            &[1, 0, 0, 0],
+            // custom section ".debug_info" with no payload
+            &[0, 12, 11],
+            b".debug_info",
+            // export section: "new" (function 0) and "memory" (memory 0)
+            &[7, 15, 2, 3],
+            b"new",
+            &[0, 0, 6],
+            b"memory",
+            &[2, 0],
+        ]
+        .concat();
+        assert_eq!(
+            parse_wasm_module(&code),
+            Ok(WasmModuleSummary {
+                exported_functions: vec!["new".to_string()],
+                has_debug_info: true,
+            })
+        );
+    }
+    #[test]
+    fn parse_wasm_module_not_wasm() {
+        assert!(parse_wasm_module(b"fn main() {}").is_err());
+    }
 }
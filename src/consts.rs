@@ -19,3 +19,5 @@ pub const DIR_NAME_TESTNET: &str = ".near-credentials/testnet/";
 pub const DIR_NAME_MAINNET: &str = ".near-credentials/mainnet/";
 pub const DIR_NAME_BETANET: &str = ".near-credentials/betanet/";
 pub const DIR_NAME_CUSTOM: &str = ".near-credentials/default/";
+
+pub const SAFETY_POLICY_FILE_NAME: &str = "safety-policy.json";
@@ -10,14 +10,40 @@ mod types;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = ())]
+#[interactive_clap(skip_default_from_cli)]
 struct Args {
+    /// Print the results of the view commands as plaintext (default) or json
+    #[interactive_clap(long)]
+    output_format: Option<self::common::OutputFormat>,
     #[interactive_clap(subcommand)]
     top_level_command: self::commands::TopLevelCommand,
 }
 
 impl Args {
+    fn from_cli(
+        optional_clap_variant: Option<<Args as interactive_clap::ToCli>::CliVariant>,
+        context: (),
+    ) -> color_eyre::eyre::Result<Self> {
+        let output_format = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.output_format);
+        let top_level_command =
+            match optional_clap_variant.and_then(|clap_variant| clap_variant.top_level_command) {
+                Some(cli_top_level_command) => {
+                    self::commands::TopLevelCommand::from_cli(Some(cli_top_level_command), context)?
+                }
+                None => self::commands::TopLevelCommand::choose_variant(context)?,
+            };
+        Ok(Self {
+            output_format,
+            top_level_command,
+        })
+    }
+
     async fn process(self) -> CliResult {
-        self.top_level_command.process().await
+        self.top_level_command
+            .process(self.output_format.unwrap_or_default())
+            .await
     }
 }
 
@@ -45,16 +71,22 @@ fn main() -> CliResult {
     // }
 
     let args = Args::from_cli(Some(cli), ())?;
+    let output_format = args.output_format.unwrap_or_default();
 
     let completed_cli = CliArgs::from(args.clone());
 
     let process_result = actix::System::new().block_on(args.process());
 
-    println!(
+    let console_command = format!(
         "Your console command:\n{} {}",
         std::env::args().next().as_deref().unwrap_or("./near_cli"),
         shell_words::join(&completed_cli.to_cli_args())
     );
+    // Keep stdout parseable in the JSON mode
+    match output_format {
+        self::common::OutputFormat::Plaintext => println!("{}", console_command),
+        self::common::OutputFormat::Json => eprintln!("{}", console_command),
+    }
 
     process_result
 }
@@ -0,0 +1,158 @@
+/// An amount of fungible tokens as it is typed by the user (e.g. `10.5` or `10.5 USDC`).
+/// It is converted to the token base units with the token `decimals` from `ft_metadata`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FtAmount {
+    integer_part: String,
+    fractional_part: String,
+    pub symbol: Option<String>,
+}
+
+impl FtAmount {
+    pub fn to_base_units(&self, decimals: u8) -> Result<u128, String> {
+        let fractional_part = self.fractional_part.trim_end_matches('0');
+        if fractional_part.len() > decimals as usize {
+            return Err(format!(
+                "FT Amount: too large fractional part of a number (the token has {} decimals)",
+                decimals
+            ));
+        }
+        format!(
+            "{}{:0<width$}",
+            self.integer_part,
+            fractional_part,
+            width = decimals as usize
+        )
+        .parse::<u128>()
+        .map_err(|err| format!("FT Amount: {}", err))
+    }
+}
+
+impl std::fmt::Display for FtAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.integer_part)?;
+        if !self.fractional_part.is_empty() {
+            write!(f, ".{}", self.fractional_part)?;
+        }
+        if let Some(symbol) = &self.symbol {
+            write!(f, " {}", symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for FtAmount {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (num, symbol) = s.split_at(
+            s.find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or_else(|| s.len()),
+        );
+        let symbol = symbol.trim();
+        let res_split: Vec<&str> = num.split('.').collect();
+        let (integer_part, fractional_part) = match res_split.as_slice() {
+            [integer_part] => (*integer_part, ""),
+            [integer_part, fractional_part] => (*integer_part, *fractional_part),
+            _ => return Err("FT Amount: incorrect number entered".to_string()),
+        };
+        if integer_part.is_empty()
+            || !integer_part.chars().all(|c| c.is_ascii_digit())
+            || !fractional_part.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err("FT Amount: incorrect number entered".to_string());
+        }
+        Ok(Self {
+            integer_part: integer_part.to_string(),
+            fractional_part: fractional_part.to_string(),
+            symbol: if symbol.is_empty() {
+                None
+            } else {
+                Some(symbol.to_string())
+            },
+        })
+    }
+}
+
+impl interactive_clap::ToCli for FtAmount {
+    type CliVariant = FtAmount;
+}
+
+/// An amount of fungible tokens in base units, displayed with the token `decimals` and `symbol`
+/// the same way as `NearBalance`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FtBalance {
+    pub amount: u128,
+    pub decimals: u8,
+    pub symbol: String,
+}
+
+impl std::fmt::Display for FtBalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `decimals` comes from the token contract, a u128 cannot hold 10^decimals above 38.
+        let one_token = match 10u128.checked_pow(self.decimals as u32) {
+            Some(one_token) => one_token,
+            None => return write!(f, "{} base units of {}", self.amount, self.symbol),
+        };
+        if self.amount % one_token == 0 {
+            write!(f, "{} {}", self.amount / one_token, self.symbol)
+        } else {
+            write!(
+                f,
+                "{}.{} {}",
+                self.amount / one_token,
+                format!(
+                    "{:0>width$}",
+                    self.amount % one_token,
+                    width = self.decimals as usize
+                )
+                .trim_end_matches('0'),
+                self.symbol
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ft_amount_to_base_units() {
+        let amount: FtAmount = "10.5 USDC".parse().unwrap();
+        assert_eq!(amount.symbol, Some("USDC".to_string()));
+        assert_eq!(amount.to_base_units(6), Ok(10_500_000));
+        assert_eq!("7".parse::<FtAmount>().unwrap().to_base_units(0), Ok(7));
+        assert_eq!(
+            "0.000001".parse::<FtAmount>().unwrap().to_base_units(6),
+            Ok(1)
+        );
+        assert!("0.0000001"
+            .parse::<FtAmount>()
+            .unwrap()
+            .to_base_units(6)
+            .is_err());
+        assert!("1.2.3".parse::<FtAmount>().is_err());
+        assert!(".5".parse::<FtAmount>().is_err());
+    }
+
+    #[test]
+    fn ft_balance_display() {
+        let balance = |amount| FtBalance {
+            amount,
+            decimals: 6,
+            symbol: "USDC".to_string(),
+        };
+        assert_eq!(balance(0).to_string(), "0 USDC");
+        assert_eq!(balance(10_500_000).to_string(), "10.5 USDC");
+        assert_eq!(balance(1).to_string(), "0.000001 USDC");
+        let balance_with_too_many_decimals = FtBalance {
+            amount: 15,
+            decimals: 39,
+            symbol: "BAD".to_string(),
+        };
+        assert_eq!(
+            balance_with_too_many_decimals.to_string(),
+            "15 base units of BAD"
+        );
+    }
+}
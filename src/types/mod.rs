@@ -1,8 +1,12 @@
 pub mod account_id;
 pub mod crypto_hash;
+pub mod ft_amount;
 pub mod path_buf;
 pub mod public_key;
 pub mod secret_key;
 pub mod signature;
 pub mod slip10;
+pub mod state_data_format;
+pub mod state_key_prefix;
+pub mod utc_date_time;
 pub mod vec_string;
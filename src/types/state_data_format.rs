@@ -0,0 +1,365 @@
+use std::convert::TryInto;
+
+/// The way raw bytes of a contract state key or value are rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateDataFormat {
+    Base64,
+    Utf8,
+    Hex,
+    Json,
+    Borsh(BorshSchema),
+}
+
+impl Default for StateDataFormat {
+    fn default() -> Self {
+        Self::Base64
+    }
+}
+
+impl std::fmt::Display for StateDataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Base64 => write!(f, "base64"),
+            Self::Utf8 => write!(f, "utf8"),
+            Self::Hex => write!(f, "hex"),
+            Self::Json => write!(f, "json"),
+            Self::Borsh(schema) => write!(f, "borsh:{}", schema),
+        }
+    }
+}
+
+impl std::str::FromStr for StateDataFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_lowercase().as_str() {
+            "base64" => Ok(Self::Base64),
+            "utf8" | "utf-8" | "string" => Ok(Self::Utf8),
+            "hex" => Ok(Self::Hex),
+            "json" => Ok(Self::Json),
+            _ => match s.split_once(':') {
+                Some((format, schema)) if format.eq_ignore_ascii_case("borsh") => {
+                    Ok(Self::Borsh(schema.parse()?))
+                }
+                _ => Err(format!(
+                    "State data format: <{}> is not one of base64, utf8, hex, json, borsh:<schema>",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+impl interactive_clap::ToCli for StateDataFormat {
+    type CliVariant = StateDataFormat;
+}
+
+impl StateDataFormat {
+    /// Decodes the bytes into a JSON value.
+    /// If the bytes do not match the format, the base64 representation is returned as an error.
+    pub fn decode(&self, data: &[u8]) -> Result<serde_json::Value, String> {
+        match self {
+            Self::Base64 => Ok(serde_json::Value::String(base64::encode(data))),
+            Self::Hex => Ok(serde_json::Value::String(hex::encode(data))),
+            Self::Utf8 => std::str::from_utf8(data)
+                .map(|text| serde_json::Value::String(text.to_owned()))
+                .map_err(|_| base64::encode(data)),
+            Self::Json => serde_json::from_slice(data).map_err(|_| base64::encode(data)),
+            Self::Borsh(schema) => {
+                let mut reader = data;
+                match schema.decode(&mut reader) {
+                    Ok(value) if reader.is_empty() => Ok(value),
+                    _ => Err(base64::encode(data)),
+                }
+            }
+        }
+    }
+
+    /// Renders the bytes for a human, marking the values that could not be decoded.
+    pub fn to_display_string(&self, data: &[u8]) -> String {
+        match self.decode(data) {
+            Ok(serde_json::Value::String(text)) => text,
+            Ok(value) => value.to_string(),
+            Err(base64_data) => format!("base64:{} (not {})", base64_data, self),
+        }
+    }
+}
+
+/// A tiny description of a borsh-serialized type, e.g. `u64`, `string`, `vec<u8>`,
+/// `option<account_id>`, `(string,u128)` or `[u8;32]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorshSchema {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    String,
+    Vec(Box<BorshSchema>),
+    Option(Box<BorshSchema>),
+    Array(Box<BorshSchema>, usize),
+    Tuple(Vec<BorshSchema>),
+}
+
+impl std::fmt::Display for BorshSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::I8 => write!(f, "i8"),
+            Self::I16 => write!(f, "i16"),
+            Self::I32 => write!(f, "i32"),
+            Self::I64 => write!(f, "i64"),
+            Self::I128 => write!(f, "i128"),
+            Self::String => write!(f, "string"),
+            Self::Vec(item) => write!(f, "vec<{}>", item),
+            Self::Option(item) => write!(f, "option<{}>", item),
+            Self::Array(item, len) => write!(f, "[{};{}]", item, len),
+            Self::Tuple(items) => write!(
+                f,
+                "({})",
+                items
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+        }
+    }
+}
+
+impl std::str::FromStr for BorshSchema {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let schema: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let (parsed_schema, rest) = Self::parse(&schema.to_lowercase())?;
+        if rest.is_empty() {
+            Ok(parsed_schema)
+        } else {
+            Err(format!("Borsh schema: unexpected <{}> in <{}>", rest, s))
+        }
+    }
+}
+
+impl BorshSchema {
+    fn parse(s: &str) -> Result<(Self, &str), String> {
+        if let Some(rest) = s.strip_prefix("vec<") {
+            let (item, rest) = Self::parse(rest)?;
+            let rest = rest
+                .strip_prefix('>')
+                .ok_or_else(|| "Borsh schema: missing '>' after vec item".to_string())?;
+            return Ok((Self::Vec(Box::new(item)), rest));
+        }
+        if let Some(rest) = s.strip_prefix("option<") {
+            let (item, rest) = Self::parse(rest)?;
+            let rest = rest
+                .strip_prefix('>')
+                .ok_or_else(|| "Borsh schema: missing '>' after option item".to_string())?;
+            return Ok((Self::Option(Box::new(item)), rest));
+        }
+        if let Some(rest) = s.strip_prefix('[') {
+            let (item, rest) = Self::parse(rest)?;
+            let rest = rest
+                .strip_prefix(';')
+                .ok_or_else(|| "Borsh schema: missing ';' in array".to_string())?;
+            let end = rest
+                .find(']')
+                .ok_or_else(|| "Borsh schema: missing ']' after array".to_string())?;
+            let len = rest[..end]
+                .parse::<usize>()
+                .map_err(|err| format!("Borsh schema: array length: {}", err))?;
+            return Ok((Self::Array(Box::new(item), len), &rest[end + 1..]));
+        }
+        if let Some(mut rest) = s.strip_prefix('(') {
+            let mut items = vec![];
+            loop {
+                let (item, next) = Self::parse(rest)?;
+                items.push(item);
+                if let Some(next) = next.strip_prefix(',') {
+                    rest = next;
+                } else if let Some(next) = next.strip_prefix(')') {
+                    return Ok((Self::Tuple(items), next));
+                } else {
+                    return Err("Borsh schema: missing ')' after tuple".to_string());
+                }
+            }
+        }
+        let end = s
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or_else(|| s.len());
+        let schema = match &s[..end] {
+            "bool" => Self::Bool,
+            "u8" => Self::U8,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "u128" => Self::U128,
+            "i8" => Self::I8,
+            "i16" => Self::I16,
+            "i32" => Self::I32,
+            "i64" => Self::I64,
+            "i128" => Self::I128,
+            "string" | "account_id" => Self::String,
+            unknown => return Err(format!("Borsh schema: unknown type <{}>", unknown)),
+        };
+        Ok((schema, &s[end..]))
+    }
+
+    fn take<'a>(reader: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+        if reader.len() < len {
+            return Err("Borsh: unexpected end of data".to_string());
+        }
+        let (head, tail) = reader.split_at(len);
+        *reader = tail;
+        Ok(head)
+    }
+
+    fn decode_len(reader: &mut &[u8]) -> Result<usize, String> {
+        let len_bytes: [u8; 4] = Self::take(reader, 4)?.try_into().unwrap();
+        Ok(u32::from_le_bytes(len_bytes) as usize)
+    }
+
+    /// Reads one value of this schema from the beginning of the reader.
+    pub fn decode(&self, reader: &mut &[u8]) -> Result<serde_json::Value, String> {
+        macro_rules! number {
+            ($type:ty, $len:expr) => {{
+                let bytes: [u8; $len] = Self::take(reader, $len)?.try_into().unwrap();
+                <$type>::from_le_bytes(bytes)
+            }};
+        }
+        Ok(match self {
+            Self::Bool => match Self::take(reader, 1)?[0] {
+                0 => serde_json::Value::Bool(false),
+                1 => serde_json::Value::Bool(true),
+                _ => return Err("Borsh: invalid bool".to_string()),
+            },
+            Self::U8 => serde_json::json!(number!(u8, 1)),
+            Self::U16 => serde_json::json!(number!(u16, 2)),
+            Self::U32 => serde_json::json!(number!(u32, 4)),
+            Self::U64 => serde_json::json!(number!(u64, 8)),
+            // u128 does not fit into JSON numbers, so it is rendered as a string (NEAR convention)
+            Self::U128 => serde_json::Value::String(number!(u128, 16).to_string()),
+            Self::I8 => serde_json::json!(number!(i8, 1)),
+            Self::I16 => serde_json::json!(number!(i16, 2)),
+            Self::I32 => serde_json::json!(number!(i32, 4)),
+            Self::I64 => serde_json::json!(number!(i64, 8)),
+            Self::I128 => serde_json::Value::String(number!(i128, 16).to_string()),
+            Self::String => {
+                let len = Self::decode_len(reader)?;
+                let bytes = Self::take(reader, len)?;
+                serde_json::Value::String(
+                    String::from_utf8(bytes.to_vec())
+                        .map_err(|err| format!("Borsh: invalid string: {}", err))?,
+                )
+            }
+            Self::Vec(item) => {
+                let len = Self::decode_len(reader)?;
+                let mut items = vec![];
+                for _ in 0..len {
+                    let remaining_len = reader.len();
+                    items.push(item.decode(reader)?);
+                    // A corrupt length of a vector of zero-sized items would otherwise produce
+                    // up to u32::MAX values without reading a byte (borsh rejects them too).
+                    if reader.len() == remaining_len {
+                        return Err(
+                            "Borsh: vectors of zero-sized items are not supported".to_string()
+                        );
+                    }
+                }
+                serde_json::Value::Array(items)
+            }
+            Self::Option(item) => match Self::take(reader, 1)?[0] {
+                0 => serde_json::Value::Null,
+                1 => item.decode(reader)?,
+                _ => return Err("Borsh: invalid option tag".to_string()),
+            },
+            Self::Array(item, len) => serde_json::Value::Array(
+                (0..*len)
+                    .map(|_| item.decode(reader))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::Tuple(items) => serde_json::Value::Array(
+                items
+                    .iter()
+                    .map(|item| item.decode(reader))
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn state_data_format_from_str_borsh_schema() {
+        assert_eq!(
+            StateDataFormat::from_str("borsh: (string, Vec<u128>)").unwrap(),
+            StateDataFormat::Borsh(BorshSchema::Tuple(vec![
+                BorshSchema::String,
+                BorshSchema::Vec(Box::new(BorshSchema::U128))
+            ]))
+        )
+    }
+    #[test]
+    fn state_data_format_from_str_unknown_format() {
+        assert!(StateDataFormat::from_str("yaml").is_err())
+    }
+    #[test]
+    fn borsh_schema_from_str_unknown_type() {
+        assert!(BorshSchema::from_str("vec<f32>").is_err())
+    }
+    #[test]
+    fn borsh_schema_to_string_roundtrip() {
+        let schema = "([u8;32],option<string>,bool)";
+        assert_eq!(BorshSchema::from_str(schema).unwrap().to_string(), schema)
+    }
+    #[test]
+    fn state_data_format_decode_borsh() {
+        let format = StateDataFormat::from_str("borsh:(string,u128)").unwrap();
+        let data = [
+            &3u32.to_le_bytes()[..],
+            b"bob",
+            &1_000_000_000_000_000_000_000_000u128.to_le_bytes()[..],
+        ]
+        .concat();
+        assert_eq!(
+            format.decode(&data),
+            Ok(serde_json::json!(["bob", "1000000000000000000000000"]))
+        )
+    }
+    #[test]
+    fn state_data_format_decode_borsh_trailing_bytes() {
+        let format = StateDataFormat::from_str("borsh:u8").unwrap();
+        assert_eq!(format.decode(&[1, 2]), Err(base64::encode(&[1, 2])))
+    }
+    #[test]
+    fn borsh_schema_decode_vec_of_zero_sized_items() {
+        let schema = BorshSchema::from_str("vec<[u8;0]>").unwrap();
+        let data = u32::MAX.to_le_bytes();
+        assert!(schema.decode(&mut &data[..]).is_err());
+        let data = 0u32.to_le_bytes();
+        assert_eq!(schema.decode(&mut &data[..]), Ok(serde_json::json!([])))
+    }
+    #[test]
+    fn state_data_format_to_display_string_invalid_utf8() {
+        assert_eq!(
+            StateDataFormat::Utf8.to_display_string(&[0xff]),
+            "base64:/w== (not utf8)".to_string()
+        )
+    }
+}
@@ -0,0 +1,47 @@
+/// A prefix of contract state keys. It is typed as a UTF-8 string, or as `hex:...` / `base64:...`
+/// for binary prefixes (e.g. the ones used by near-sdk collections).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StateKeyPrefix(pub Vec<u8>);
+
+impl std::fmt::Display for StateKeyPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match std::str::from_utf8(&self.0) {
+            Ok(prefix)
+                if !prefix.starts_with("hex:")
+                    && !prefix.starts_with("base64:")
+                    && prefix.chars().all(|c| !c.is_control()) =>
+            {
+                write!(f, "{}", prefix)
+            }
+            _ => write!(f, "base64:{}", base64::encode(&self.0)),
+        }
+    }
+}
+
+impl std::str::FromStr for StateKeyPrefix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex_prefix) = s.strip_prefix("hex:") {
+            Ok(Self(hex::decode(hex_prefix).map_err(|err| {
+                format!("State key prefix: invalid hex: {}", err)
+            })?))
+        } else if let Some(base64_prefix) = s.strip_prefix("base64:") {
+            Ok(Self(base64::decode(base64_prefix).map_err(|err| {
+                format!("State key prefix: invalid base64: {}", err)
+            })?))
+        } else {
+            Ok(Self(s.as_bytes().to_vec()))
+        }
+    }
+}
+
+impl From<StateKeyPrefix> for near_primitives::types::StoreKey {
+    fn from(item: StateKeyPrefix) -> Self {
+        near_primitives::types::StoreKey::from(item.0)
+    }
+}
+
+impl interactive_clap::ToCli for StateKeyPrefix {
+    type CliVariant = StateKeyPrefix;
+}
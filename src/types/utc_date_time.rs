@@ -0,0 +1,314 @@
+use std::convert::TryFrom;
+
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+const SECONDS_IN_DAY: u64 = 24 * 60 * 60;
+
+/// A date and time in UTC (e.g. `2022-01-31`, `2022-01-31 23:59:59` or `2022-01-31T23:59:59Z`),
+/// kept as the number of nanoseconds since the Unix epoch, the same way as the block timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct UtcDateTime {
+    pub timestamp_nanosec: u64,
+}
+
+impl UtcDateTime {
+    pub fn from_timestamp_nanosec(timestamp_nanosec: u64) -> Self {
+        Self { timestamp_nanosec }
+    }
+
+    fn from_parts(year: i64, month: u32, day: u32, seconds_of_day: u64) -> Result<Self, String> {
+        let days = u64::try_from(days_from_civil(year, month, day))
+            .map_err(|_| "Date: dates before 1970-01-01 are not supported".to_string())?;
+        days.checked_mul(SECONDS_IN_DAY)
+            .and_then(|seconds| seconds.checked_add(seconds_of_day))
+            .and_then(|seconds| seconds.checked_mul(NANOSECONDS_IN_SECOND))
+            .map(Self::from_timestamp_nanosec)
+            .ok_or_else(|| "Date: dates after 2554-07-21 are not supported".to_string())
+    }
+
+    fn parts(&self) -> (i64, u32, u32, u64) {
+        let seconds = self.timestamp_nanosec / NANOSECONDS_IN_SECOND;
+        let (year, month, day) = civil_from_days((seconds / SECONDS_IN_DAY) as i64);
+        (year, month, day, seconds % SECONDS_IN_DAY)
+    }
+
+    /// Adds the interval; months keep the day of the month of `start` when possible,
+    /// otherwise the last day of the month is used (Jan 31 + 1 month = Feb 28).
+    pub fn add_interval(
+        start: Self,
+        interval: &DateTimeInterval,
+        count: u64,
+    ) -> Result<Self, String> {
+        let out_of_range_error = || {
+            format!(
+                "Date: {} + {} x {} is after 2554-07-21, which is not supported",
+                start, count, interval
+            )
+        };
+        let add_seconds = |seconds_in_interval: u64, number: u64| {
+            number
+                .checked_mul(count)
+                .and_then(|number| number.checked_mul(seconds_in_interval))
+                .and_then(|seconds| seconds.checked_mul(NANOSECONDS_IN_SECOND))
+                .and_then(|nanoseconds| start.timestamp_nanosec.checked_add(nanoseconds))
+                .map(Self::from_timestamp_nanosec)
+                .ok_or_else(out_of_range_error)
+        };
+        match interval {
+            DateTimeInterval::Hours(hours) => add_seconds(3600, *hours),
+            DateTimeInterval::Days(days) => add_seconds(SECONDS_IN_DAY, *days),
+            DateTimeInterval::Months(months) => {
+                let (year, month, day, seconds_of_day) = start.parts();
+                let month_index = months
+                    .checked_mul(count)
+                    .and_then(|months| i64::try_from(months).ok())
+                    .and_then(|months| (year * 12 + (month as i64 - 1)).checked_add(months))
+                    .ok_or_else(out_of_range_error)?;
+                let (year, month) = (month_index / 12, (month_index % 12) as u32 + 1);
+                let day = std::cmp::min(day, days_in_month(year, month));
+                Self::from_parts(year, month, day, seconds_of_day).map_err(|_| out_of_range_error())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for UtcDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (year, month, day, seconds_of_day) = self.parts();
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+            year,
+            month,
+            day,
+            seconds_of_day / 3600,
+            seconds_of_day / 60 % 60,
+            seconds_of_day % 60
+        )
+    }
+}
+
+impl std::str::FromStr for UtcDateTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .trim()
+            .trim_end_matches("UTC")
+            .trim()
+            .trim_end_matches('Z');
+        let (date, time) = match s.find(&['T', ' '][..]) {
+            Some(index) => (&s[..index], s[index + 1..].trim()),
+            None => (s, ""),
+        };
+        let parse_number = |value: &str| {
+            value.parse::<u32>().map_err(|_| {
+                format!(
+                    "Date: <{}> is not a valid date and time (use YYYY-MM-DD HH:MM:SS)",
+                    s
+                )
+            })
+        };
+        let date_parts = date
+            .split('-')
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+        let (year, month, day) = match date_parts.as_slice() {
+            [year, month, day] => (*year as i64, *month, *day),
+            _ => {
+                return Err(format!(
+                    "Date: <{}> is not a valid date (use YYYY-MM-DD)",
+                    date
+                ))
+            }
+        };
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return Err(format!("Date: <{}> is not a valid date", date));
+        }
+        let time_parts = if time.is_empty() {
+            vec![]
+        } else {
+            time.split(':')
+                .map(parse_number)
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let (hours, minutes, seconds) = match time_parts.as_slice() {
+            [] => (0, 0, 0),
+            [hours, minutes] => (*hours, *minutes, 0),
+            [hours, minutes, seconds] => (*hours, *minutes, *seconds),
+            _ => {
+                return Err(format!(
+                    "Date: <{}> is not a valid time (use HH:MM:SS)",
+                    time
+                ))
+            }
+        };
+        if hours > 23 || minutes > 59 || seconds > 59 {
+            return Err(format!("Date: <{}> is not a valid time", time));
+        }
+        Self::from_parts(
+            year,
+            month,
+            day,
+            (hours * 3600 + minutes * 60 + seconds) as u64,
+        )
+    }
+}
+
+impl interactive_clap::ToCli for UtcDateTime {
+    type CliVariant = UtcDateTime;
+}
+
+/// An interval between the samples of a history (e.g. `12h`, `1d`, `7d` or `1month`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateTimeInterval {
+    Hours(u64),
+    Days(u64),
+    Months(u64),
+}
+
+impl std::fmt::Display for DateTimeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Hours(hours) => write!(f, "{}h", hours),
+            Self::Days(days) => write!(f, "{}d", days),
+            Self::Months(months) => write!(f, "{}month", months),
+        }
+    }
+}
+
+impl std::str::FromStr for DateTimeInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        let (number, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+        let number: u64 = number
+            .parse()
+            .map_err(|_| format!("Interval: <{}> has no number (e.g. 1d)", s))?;
+        if number == 0 {
+            return Err("Interval: must be greater than zero".to_string());
+        }
+        match unit.trim() {
+            "h" | "hour" | "hours" => Ok(Self::Hours(number)),
+            "d" | "day" | "days" => Ok(Self::Days(number)),
+            "month" | "months" => Ok(Self::Months(number)),
+            _ => Err(format!(
+                "Interval: <{}> is not supported (use hours, days or months, e.g. 12h, 1d, 1month)",
+                s
+            )),
+        }
+    }
+}
+
+impl interactive_clap::ToCli for DateTimeInterval {
+    type CliVariant = DateTimeInterval;
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_date_time_from_str() {
+        let date_time: UtcDateTime = "2022-01-31 23:59:59".parse().unwrap();
+        assert_eq!(
+            date_time.timestamp_nanosec,
+            1_643_673_599 * NANOSECONDS_IN_SECOND
+        );
+        assert_eq!(date_time.to_string(), "2022-01-31 23:59:59 UTC");
+        assert_eq!(
+            "2022-01-31T23:59:59Z".parse::<UtcDateTime>().unwrap(),
+            date_time
+        );
+        assert_eq!(
+            "2020-02-29".parse::<UtcDateTime>().unwrap().to_string(),
+            "2020-02-29 00:00:00 UTC"
+        );
+        assert!("2021-02-29".parse::<UtcDateTime>().is_err());
+        assert!("2022-01-31 24:00".parse::<UtcDateTime>().is_err());
+        assert!("31.01.2022".parse::<UtcDateTime>().is_err());
+        assert!("1969-12-31".parse::<UtcDateTime>().is_err());
+        assert!("2554-07-21".parse::<UtcDateTime>().is_ok());
+        assert!("2554-07-22".parse::<UtcDateTime>().is_err());
+        assert!("4294967295-01-01".parse::<UtcDateTime>().is_err());
+    }
+
+    #[test]
+    fn utc_date_time_add_interval() {
+        let start: UtcDateTime = "2022-01-31 23:59:59".parse().unwrap();
+        let months = "1month".parse::<DateTimeInterval>().unwrap();
+        assert_eq!(
+            UtcDateTime::add_interval(start, &months, 1)
+                .unwrap()
+                .to_string(),
+            "2022-02-28 23:59:59 UTC"
+        );
+        assert_eq!(
+            UtcDateTime::add_interval(start, &months, 2)
+                .unwrap()
+                .to_string(),
+            "2022-03-31 23:59:59 UTC"
+        );
+        assert_eq!(
+            UtcDateTime::add_interval(start, &months, 11)
+                .unwrap()
+                .to_string(),
+            "2022-12-31 23:59:59 UTC"
+        );
+        assert_eq!(
+            UtcDateTime::add_interval(start, &"12h".parse().unwrap(), 1)
+                .unwrap()
+                .to_string(),
+            "2022-02-01 11:59:59 UTC"
+        );
+        assert!(UtcDateTime::add_interval(start, &"1d".parse().unwrap(), u64::MAX).is_err());
+        assert!(UtcDateTime::add_interval(start, &"1month".parse().unwrap(), 10_000).is_err());
+        assert!(UtcDateTime::add_interval(start, &"1month".parse().unwrap(), u64::MAX).is_err());
+        assert!("0d".parse::<DateTimeInterval>().is_err());
+        assert!("1w".parse::<DateTimeInterval>().is_err());
+    }
+}